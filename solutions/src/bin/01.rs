@@ -65,6 +65,7 @@ impl Digit {
     /// Used correctly, the input string should only contain one digit. Otherwise the output may be unexpected.
     ///
     /// Designed for part 1, only checks for digit based on the digit itself, not the spelled word.
+    #[allow(dead_code)]
     pub fn parse_part_1(input: &str) -> Option<Self> {
         use Digit::*;
         [One, Two, Three, Four, Five, Six, Seven, Eight, Nine]
@@ -17,15 +17,7 @@ struct GameLine {
 impl FromStr for GameLine {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (game_tag, reveals) = s
-            .split_once(':')
-            .with_context(|| "Unable to split line by ':'")?;
-
-        let id = game_tag
-            .strip_prefix("Game")
-            .with_context(|| "Unable to remove 'Game' prefix!")?
-            .trim()
-            .parse::<Num>()?;
+        let (id, reveals) = scan!(s, "Game {}: {}", id: Num, reveals: &str)?;
 
         let cube_sets = reveals
             .split(';')
@@ -64,21 +56,14 @@ impl FromStr for CubeCount {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let input = s.to_lowercase().trim().to_owned();
-
-        let (count, cube) = input
-            .split_once(' ')
-            .with_context(|| format!("Unable to split reveal string by space: '{}'", s))?;
-
-        let count = count
-            .parse::<Num>()
-            .with_context(|| format!("Couldn't parse count for reveal: '{}'", count))?;
+        let (count, cube) = scan!(&input, "{} {}", count: Num, cube: &str)?;
 
         CubeCount::parse_cube(count, cube).context("Couldn't parse cube for reveal!")
     }
 }
 
 pub fn part_one(input: &str) -> anyhow::Result<Num> {
-    let games = input.lines().map(str::parse::<GameLine>);
+    let games = parse_lines(input, str::parse::<GameLine>);
 
     const MAX_SET: CubeSet = CubeSet {
         red: Some(12),
@@ -222,7 +207,7 @@ impl CubeSetMax for Vec<CubeSet> {
 }
 
 pub fn part_two(input: &str) -> anyhow::Result<Num> {
-    let games = input.lines().map(str::parse::<GameLine>);
+    let games = parse_lines(input, str::parse::<GameLine>);
 
     games
         .debug_inspect(|game| println!("Game lines: {game:?}"))
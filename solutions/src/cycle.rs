@@ -0,0 +1,171 @@
+//! Shortcuts for puzzles that ask for the state after a huge number of steps of a simulation that eventually repeats.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// A cycle in a sequence of states: from step `start` on, the states repeat every `length` steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Run `step` until a state repeats.
+/// ```
+/// # use solutions::prelude::*;
+/// let cycle = find_cycle(0, |n| (n + 3) % 10 + 2);
+/// assert_eq!(cycle, Cycle { start: 1, length: 2 });
+/// ```
+pub fn find_cycle<S>(initial: S, step: impl FnMut(&S) -> S) -> Cycle
+where
+    S: Clone + Eq + Hash,
+{
+    let (_, cycle) = simulate(initial, usize::MAX, step, Clone::clone);
+    cycle.expect("simulation ended without repeating.")
+}
+
+/// The state after running `step` for `steps` times, skipping ahead as soon as the states repeat.
+pub fn state_after<S>(initial: S, steps: usize, step: impl FnMut(&S) -> S) -> S
+where
+    S: Clone + Eq + Hash,
+{
+    let (mut history, cycle) = simulate(initial, steps, step, Clone::clone);
+    let index = cycle.map_or(steps, |cycle| cycle.equivalent_step(steps));
+    history.swap_remove(index)
+}
+
+/// Extrapolate a quantity that grows by the same amount in every cycle, e.g. the height of a tower after a trillion blocks fell.
+/// The cycle is detected on the `key` of the states, which must not include the growing quantity itself.
+pub fn extrapolate<S, K>(
+    initial: S,
+    steps: usize,
+    step: impl FnMut(&S) -> S,
+    key: impl FnMut(&S) -> K,
+    mut value: impl FnMut(&S) -> i64,
+) -> i64
+where
+    K: Eq + Hash,
+{
+    let (history, cycle) = simulate(initial, steps, step, key);
+
+    let Some(cycle) = cycle else {
+        return value(&history[steps]);
+    };
+
+    let cycles = ((steps - cycle.start) / cycle.length) as i64;
+    let growth = value(&history[cycle.start + cycle.length]) - value(&history[cycle.start]);
+
+    value(&history[cycle.equivalent_step(steps)]) + cycles * growth
+}
+
+/// Run `step` until a state's key repeats or `steps` steps are done.
+/// Returns all states seen, including the repeated one.
+fn simulate<S, K>(
+    initial: S,
+    steps: usize,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> (Vec<S>, Option<Cycle>)
+where
+    K: Eq + Hash,
+{
+    let mut seen: HashMap<K, usize> = HashMap::from([(key(&initial), 0)]);
+    let mut history = vec![initial];
+
+    while history.len() <= steps {
+        let next = step(history.last().unwrap());
+        let index = history.len();
+
+        if let Some(&start) = seen.get(&key(&next)) {
+            history.push(next);
+            return (
+                history,
+                Some(Cycle {
+                    start,
+                    length: index - start,
+                }),
+            );
+        }
+
+        seen.insert(key(&next), index);
+        history.push(next);
+    }
+
+    (history, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extrapolate, find_cycle, state_after, Cycle};
+
+    fn step(n: &u64) -> u64 {
+        (n * n + 7) % 1009
+    }
+
+    fn brute_force(steps: usize) -> u64 {
+        (0..steps).fold(3, |n, _| step(&n))
+    }
+
+    #[test]
+    fn finds_cycle() {
+        let cycle = find_cycle(3, step);
+        let after_start = state_after(3, cycle.start, step);
+        assert_eq!(
+            state_after(3, cycle.start + cycle.length, step),
+            after_start
+        );
+        assert_eq!(
+            Cycle {
+                start: 2,
+                length: 3
+            }
+            .equivalent_step(10),
+            4
+        );
+    }
+
+    #[test]
+    fn skips_to_state() {
+        for steps in [0, 1, 5, 100, 1000, 12345] {
+            assert_eq!(state_after(3, steps, step), brute_force(steps));
+        }
+        assert_eq!(
+            state_after(3, 1_000_000_000, step),
+            state_after(3, find_cycle(3, step).equivalent_step(1_000_000_000), step)
+        );
+    }
+
+    #[test]
+    fn extrapolates_quantity() {
+        // walk around a ring of 7 cells, summing up the cell numbers.
+        let walk = |&(position, sum): &(i64, i64)| ((position + 3) % 7, sum + position);
+        let brute_force = |steps: usize| (0..steps).fold((2, 0), |state, _| walk(&state)).1;
+
+        for steps in [0, 3, 7, 50, 1234, 1_000_000] {
+            assert_eq!(
+                extrapolate((2, 0), steps, walk, |&(p, _)| p, |&(_, sum)| sum),
+                brute_force(steps)
+            );
+        }
+        assert_eq!(
+            extrapolate(
+                (2, 0),
+                1_000_000_000_000,
+                walk,
+                |&(p, _)| p,
+                |&(_, sum)| sum
+            ),
+            142_857_142_857 * 21 + 2
+        );
+    }
+}
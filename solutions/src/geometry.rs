@@ -0,0 +1,213 @@
+//! Polygons on integer grids, for puzzles about loops enclosing tiles or digging out a lagoon.
+//!
+//! Like in [`Point2`], `y` grows downwards, so polygons listed clockwise on screen have a positive orientation.
+
+use crate::{
+    math::gcd,
+    point::{Direction, Point2},
+};
+
+/// A closed polygon given by its corners in order. The last corner connects back to the first one.
+/// ```
+/// # use solutions::prelude::*;
+/// let steps = [(Direction::Right, 4), (Direction::Down, 2), (Direction::Left, 4)];
+/// let polygon = Polygon::from_steps(Point2::ORIGIN, steps);
+/// assert_eq!(polygon.area(), 8);
+/// assert_eq!(polygon.boundary_points(), 12);
+/// assert_eq!(polygon.interior_points(), 3);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    pub vertices: Vec<Point2>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point2>) -> Self {
+        Self { vertices }
+    }
+
+    /// Follow a list of moves, e.g. a dig plan, from `start` and use the points where they end as corners.
+    /// If the moves return to `start`, it is not repeated as the last corner.
+    pub fn from_steps(start: Point2, steps: impl IntoIterator<Item = (Direction, i64)>) -> Self {
+        let mut vertices = vec![start];
+        let mut position = start;
+
+        for (direction, count) in steps {
+            position = position.step(direction, count);
+            vertices.push(position);
+        }
+
+        if vertices.len() > 1 && vertices.last() == Some(&start) {
+            vertices.pop();
+        }
+
+        Self { vertices }
+    }
+
+    /// Twice the signed area, using the shoelace formula. Doubled, it is always an integer, even for shapes like triangles.
+    /// Positive if the corners are listed clockwise on screen, negative if counter-clockwise.
+    pub fn double_signed_area(&self) -> i64 {
+        self.edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum()
+    }
+
+    /// The area enclosed by the edges, rounded down. Exact if all edges are horizontal or vertical.
+    pub fn area(&self) -> i64 {
+        self.double_signed_area().abs() / 2
+    }
+
+    /// Number of integer points on the edges, including the corners.
+    pub fn boundary_points(&self) -> i64 {
+        self.edges().map(|(a, b)| gcd(b.x - a.x, b.y - a.y)).sum()
+    }
+
+    /// Number of integer points strictly inside the polygon, using Pick's theorem.
+    /// The edges must not cross each other.
+    pub fn interior_points(&self) -> i64 {
+        (self.double_signed_area().abs() - self.boundary_points() + 2) / 2
+    }
+
+    /// Total length of the edges.
+    pub fn perimeter(&self) -> f64 {
+        self.edges()
+            .map(|(a, b)| {
+                let d = b - a;
+                ((d.x * d.x + d.y * d.y) as f64).sqrt()
+            })
+            .sum()
+    }
+
+    /// How often the edges wind around `point`, positive for clockwise on screen.
+    /// Points on an edge have no well-defined winding number, check them with [`Polygon::on_boundary`].
+    pub fn winding_number(&self, point: Point2) -> i64 {
+        let mut winding = 0;
+
+        for (a, b) in self.edges() {
+            let side = cross(a, b, point);
+            if a.y <= point.y {
+                if b.y > point.y && side > 0 {
+                    winding += 1;
+                }
+            } else if b.y <= point.y && side < 0 {
+                winding -= 1;
+            }
+        }
+
+        winding
+    }
+
+    /// Whether `point` lies on one of the edges.
+    pub fn on_boundary(&self, point: Point2) -> bool {
+        self.edges().any(|(a, b)| {
+            cross(a, b, point) == 0
+                && (a.x.min(b.x)..=a.x.max(b.x)).contains(&point.x)
+                && (a.y.min(b.y)..=a.y.max(b.y)).contains(&point.y)
+        })
+    }
+
+    /// Whether `point` lies strictly inside the polygon. Points on the edges are not enclosed.
+    pub fn encloses(&self, point: Point2) -> bool {
+        !self.on_boundary(point) && self.winding_number(point) != 0
+    }
+
+    /// Pairs of consecutive corners, including the edge from the last corner back to the first.
+    fn edges(&self) -> impl Iterator<Item = (Point2, Point2)> + '_ {
+        self.vertices
+            .iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+}
+
+impl FromIterator<Point2> for Polygon {
+    fn from_iter<I: IntoIterator<Item = Point2>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// Positive if `point` is to the right of the line from `a` to `b` on screen, negative if to the left and 0 if on it.
+fn cross(a: Point2, b: Point2, point: Point2) -> i64 {
+    (b.x - a.x) * (point.y - a.y) - (point.x - a.x) * (b.y - a.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Polygon;
+    use crate::point::{Direction, Point2};
+
+    fn square() -> Polygon {
+        [(0, 0), (4, 0), (4, 4), (0, 4)]
+            .into_iter()
+            .map(|(x, y)| Point2::new(x, y))
+            .collect()
+    }
+
+    #[test]
+    fn calculates_area() {
+        let square = square();
+        assert_eq!(square.double_signed_area(), 32);
+        assert_eq!(square.area(), 16);
+        assert_eq!(square.perimeter(), 16.0);
+
+        let reversed = Polygon::new(square.vertices.iter().rev().copied().collect());
+        assert_eq!(reversed.double_signed_area(), -32);
+        assert_eq!(reversed.area(), 16);
+
+        let triangle = Polygon::new(vec![
+            Point2::new(0, 0),
+            Point2::new(3, 0),
+            Point2::new(0, 4),
+        ]);
+        assert_eq!(triangle.perimeter(), 12.0);
+        assert_eq!(triangle.boundary_points(), 8);
+        assert_eq!(triangle.interior_points(), 3);
+    }
+
+    #[test]
+    fn counts_lattice_points() {
+        // dig plan of 2023 day 18.
+        let plan = "R 6,D 5,L 2,D 2,R 2,D 2,L 5,U 2,L 1,U 2,R 2,U 3,L 2,U 2";
+        let steps = plan.split(',').map(|step| {
+            let (direction, count) = step.split_once(' ').unwrap();
+            (
+                direction.parse::<Direction>().unwrap(),
+                count.parse().unwrap(),
+            )
+        });
+
+        let lagoon = Polygon::from_steps(Point2::ORIGIN, steps);
+        assert_eq!(lagoon.vertices.len(), 14);
+        assert_eq!(lagoon.boundary_points(), 38);
+        assert_eq!(lagoon.interior_points() + lagoon.boundary_points(), 62);
+    }
+
+    #[test]
+    fn locates_points() {
+        let square = square();
+        assert_eq!(square.winding_number(Point2::new(2, 2)), 1);
+        assert!(square.encloses(Point2::new(1, 3)));
+        assert!(!square.encloses(Point2::new(4, 2)));
+        assert!(square.on_boundary(Point2::new(4, 2)));
+        assert!(!square.encloses(Point2::new(5, 2)));
+
+        // a U shape, the gap at the top is outside.
+        let u = Polygon::from_steps(
+            Point2::ORIGIN,
+            [
+                (Direction::Right, 2),
+                (Direction::Down, 4),
+                (Direction::Right, 2),
+                (Direction::Up, 4),
+                (Direction::Right, 2),
+                (Direction::Down, 6),
+                (Direction::Left, 6),
+                (Direction::Up, 6),
+            ],
+        );
+        assert!(u.encloses(Point2::new(1, 2)));
+        assert!(!u.encloses(Point2::new(3, 2)));
+        assert!(u.encloses(Point2::new(3, 5)));
+
+        let reversed = Polygon::new(u.vertices.iter().rev().copied().collect());
+        assert_eq!(reversed.winding_number(Point2::new(1, 2)), -1);
+    }
+}
@@ -0,0 +1,509 @@
+//! A dense two-dimensional grid, the shape most puzzle inputs come in.
+//!
+//! Positions are `(x, y)` pairs, where `x` is the column and `y` the row, starting at the top left.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use anyhow::{bail, Context};
+
+/// Offsets of the neighbours sharing an edge with a cell, clockwise starting at the top.
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the neighbours sharing an edge or a corner with a cell, clockwise starting at the top.
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// Types that can be used as a position in a grid.
+/// Signed positions may point outside of the grid, e.g. after stepping off an edge.
+pub trait GridIndex: Copy {
+    /// The `(x, y)` cell of this position, if it lies within a grid of the given size.
+    fn cell(self, width: usize, height: usize) -> Option<(usize, usize)>;
+
+    /// The `(x, y)` cell of this position, wrapping around the edges of a grid of the given size.
+    fn wrapping_cell(self, width: usize, height: usize) -> (usize, usize);
+}
+
+macro_rules! impl_grid_index {
+    ($($t:ty),*) => {
+        $(
+            impl GridIndex for ($t, $t) {
+                fn cell(self, width: usize, height: usize) -> Option<(usize, usize)> {
+                    let (x, y) = (self.0 as i128, self.1 as i128);
+                    let in_bounds = x >= 0 && y >= 0 && x < width as i128 && y < height as i128;
+                    in_bounds.then_some((x as usize, y as usize))
+                }
+
+                fn wrapping_cell(self, width: usize, height: usize) -> (usize, usize) {
+                    (
+                        (self.0 as i128).rem_euclid(width as i128) as usize,
+                        (self.1 as i128).rem_euclid(height as i128) as usize,
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_grid_index!(usize, isize, u32, i32, i64);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid by calling `f` with the position of every cell. Panics if `width` or `height` is zero.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        assert!(width > 0, "grid width must not be zero.");
+        assert!(height > 0, "grid height must not be zero.");
+
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Create a grid with every cell set to `value`. Panics if `width` or `height` is zero.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(width > 0, "grid width must not be zero.");
+        assert!(height > 0, "grid height must not be zero.");
+
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parse a grid with one row per line, mapping every character to a cell.
+    /// ```
+    /// # use solutions::prelude::*;
+    /// let grid = Grid::parse("#.\n.#\n", |c| c == '#').unwrap();
+    /// assert_eq!(grid[(1, 1)], true);
+    /// ```
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> anyhow::Result<Self> {
+        Self::try_parse(input, |c| anyhow::Ok(f(c)))
+    }
+
+    /// Parse a grid with one row per line, mapping every character to a cell with a function that may fail.
+    pub fn try_parse<E>(
+        input: &str,
+        mut f: impl FnMut(char) -> Result<T, E>,
+    ) -> anyhow::Result<Self>
+    where
+        E: Into<anyhow::Error>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (y, line) in input.lines().enumerate() {
+            let before = cells.len();
+
+            for (x, c) in line.chars().enumerate() {
+                let cell = f(c).map_err(Into::into).with_context(|| {
+                    format!("invalid cell {c:?} at line {}, column {}", y + 1, x + 1)
+                })?;
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - before;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    bail!(
+                        "line {} has {row_width} cells, but the grid is {width} cells wide.",
+                        y + 1
+                    );
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+            _ => bail!("grid is empty."),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: impl GridIndex) -> bool {
+        position.cell(self.width, self.height).is_some()
+    }
+
+    pub fn get(&self, position: impl GridIndex) -> Option<&T> {
+        let (x, y) = position.cell(self.width, self.height)?;
+        Some(&self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, position: impl GridIndex) -> Option<&mut T> {
+        let (x, y) = position.cell(self.width, self.height)?;
+        Some(&mut self.cells[y * self.width + x])
+    }
+
+    /// Get a cell of the grid repeated infinitely in all directions.
+    pub fn get_wrapping(&self, position: impl GridIndex) -> &T {
+        let (x, y) = position.wrapping_cell(self.width, self.height);
+        &self.cells[y * self.width + x]
+    }
+
+    /// Positions of the up to four cells sharing an edge with a cell.
+    pub fn neighbours4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(position, &NEIGHBOURS_4)
+    }
+
+    /// Positions of the up to eight cells sharing an edge or a corner with a cell.
+    pub fn neighbours8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(position, &NEIGHBOURS_8)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        (x, y): (usize, usize),
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            (x as isize + dx, y as isize + dy).cell(self.width, self.height)
+        })
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// The cells of column `x`, from top to bottom. Panics if `x` is out of bounds, like [`Self::row`].
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is out of bounds for a grid of width {}.",
+            self.width
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Diagonals running from the top left to the bottom right, starting with the one in the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| (0, y))
+            .chain((1..self.width).map(|x| (x, 0)));
+
+        starts.map(move |(x, y)| (0..).map_while(move |i| self.get((x + i, y + i))))
+    }
+
+    /// Diagonals running from the top right to the bottom left, starting with the one in the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.width)
+            .map(|x| (x, 0))
+            .chain((1..self.height).map(move |y| (self.width - 1, y)));
+
+        starts.map(move |(x, y)| (0..=x).map_while(move |i| self.get((x - i, y + i))))
+    }
+
+    /// Position of the first cell, row by row, that matches a predicate.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Positions of all cells that match a predicate.
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Position of the first cell, row by row, that is equal to `value`.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(&mut f).collect(),
+        }
+    }
+
+    /// Mirror the grid along the diagonal from the top left to the bottom right.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+
+    /// Mirror the grid left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.width, self.height, |(x, y)| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Mirror the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.width, self.height, |(x, y)| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+}
+
+impl<T, P: GridIndex> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: P) -> &Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get(position)
+            .unwrap_or_else(|| panic!("position is outside of the {width}x{height} grid"))
+    }
+}
+
+impl<T, P: GridIndex> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, position: P) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position is outside of the {width}x{height} grid"))
+    }
+}
+
+/// Prints one row per line, so a grid of characters prints exactly like the input it was parsed from.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    const INPUT: &str = "#..\n.#.\n#.#\n.##\n";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(INPUT, |c| c).unwrap()
+    }
+
+    #[test]
+    fn parses_and_displays() {
+        let grid = grid();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 4);
+        assert_eq!(grid[(2, 2)], '#');
+        assert_eq!(grid.to_string(), INPUT);
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert!(Grid::parse("#..\n.#\n", |c| c).is_err());
+        assert!(Grid::parse("", |c| c).is_err());
+
+        let error = Grid::try_parse("12\n3x\n", |c| {
+            c.to_digit(10).ok_or(anyhow::anyhow!("not a digit"))
+        })
+        .unwrap_err();
+        assert_eq!(error.to_string(), "invalid cell 'x' at line 2, column 2");
+    }
+
+    #[test]
+    fn indexes_with_bounds_checks() {
+        let grid = grid();
+        assert_eq!(grid.get((1, 0)), Some(&'.'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((-1, 0)), None);
+        assert_eq!(grid.get((0i64, 3i64)), Some(&'.'));
+        assert!(grid.contains((2u32, 3u32)));
+        assert!(!grid.contains((2, 4)));
+    }
+
+    #[test]
+    fn indexes_wrapping() {
+        let grid = grid();
+        assert_eq!(grid.get_wrapping((3, 0)), &'#');
+        assert_eq!(grid.get_wrapping((-1, -1)), &'#');
+        assert_eq!(grid.get_wrapping((-4, 5)), &'.');
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbours8((2, 3)).collect::<Vec<_>>(),
+            vec![(2, 2), (1, 3), (1, 2)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn iterates_rows_columns_and_diagonals() {
+        let grid = grid();
+
+        assert_eq!(grid.row(2), &['#', '.', '#']);
+        assert_eq!(grid.rows().count(), 4);
+        assert_eq!(grid.column(1).collect::<String>(), ".#.#");
+        assert_eq!(grid.columns().count(), 3);
+
+        let diagonals: Vec<String> = grid.diagonals().map(Iterator::collect).collect();
+        assert_eq!(diagonals, vec![".", "##", "..#", "###", "..", "."]);
+
+        let anti_diagonals: Vec<String> = grid.anti_diagonals().map(Iterator::collect).collect();
+        assert_eq!(anti_diagonals, vec!["#", "..", ".##", "...", "##", "#"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_column_out_of_bounds() {
+        let _ = grid().column(4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_zero_width() {
+        let _ = Grid::new(0, 3, '.');
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_zero_height() {
+        let _ = Grid::from_fn(3, 0, |_| '.');
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = Grid::parse("ab\ncd\nef\n", |c| c).unwrap();
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb\n");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "bdf\nace\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "ba\ndc\nfe\n");
+        assert_eq!(grid.flip_vertical().to_string(), "ef\ncd\nab\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+    }
+
+    #[test]
+    fn finds_positions() {
+        let grid = grid();
+        assert_eq!(grid.find(&'#'), Some((0, 0)));
+        assert_eq!(grid.position(|&c| c == '.'), Some((1, 0)));
+        assert_eq!(grid.positions(|&c| c == '#').count(), 6);
+        assert_eq!(grid.find(&'x'), None);
+    }
+
+    #[test]
+    fn creates_and_maps() {
+        let mut grid = Grid::new(2, 2, 0);
+        grid[(1, 0)] = 5;
+        assert_eq!(grid.to_string(), "05\n00\n");
+        assert_eq!(
+            Grid::from_fn(3, 1, |(x, _)| x).map(|x| x * 2).to_string(),
+            "024\n"
+        );
+    }
+}
@@ -0,0 +1,365 @@
+//! Sets of integers stored as ranges, for puzzles that push huge ranges of numbers through a series of transformations.
+//!
+//! Ranges are half-open like [`std::ops::Range`], so `3..7` contains 3, 4, 5 and 6.
+
+use std::ops::Range;
+
+/// A set of integers, stored as sorted, disjoint and non-adjacent ranges.
+/// ```
+/// # use solutions::prelude::*;
+/// let set = IntervalSet::from_iter([0..5, 10..15]);
+/// let other = IntervalSet::from(3..12);
+/// assert_eq!(set.intersection(&other), IntervalSet::from_iter([3..5, 10..12]));
+/// assert_eq!(set.union(&other), IntervalSet::from(0..15));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = &Range<i64>> {
+        self.ranges.iter()
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> i64 {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|r| r.end - 1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(index).is_some_and(|r| r.contains(&value))
+    }
+
+    pub fn insert(&mut self, range: Range<i64>) {
+        self.ranges.push(range);
+        self.normalize();
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+
+            // advance whichever range ends first, the other one might still overlap the next range.
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut others = other.ranges.iter().peekable();
+
+        for range in &self.ranges {
+            let mut start = range.start;
+
+            // skip ranges of `other` that end before this range starts.
+            while others.next_if(|o| o.end <= start).is_some() {}
+
+            for o in others.clone() {
+                if o.start >= range.end {
+                    break;
+                }
+                if o.start > start {
+                    ranges.push(start..o.start);
+                }
+                start = start.max(o.end);
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Add `offset` to every integer in the set.
+    pub fn shift(&self, offset: i64) -> Self {
+        Self {
+            ranges: self
+                .ranges
+                .iter()
+                .map(|r| r.start + offset..r.end + offset)
+                .collect(),
+        }
+    }
+
+    /// Split the set into the integers below `value` and the ones from `value` on.
+    pub fn split_at(&self, value: i64) -> (Self, Self) {
+        (
+            self.intersection(&Self::from(i64::MIN..value)),
+            self.intersection(&Self::from(value..i64::MAX)),
+        )
+    }
+
+    /// Sort and merge ranges, dropping empty ones.
+    fn normalize(&mut self) {
+        self.ranges.retain(|r| !r.is_empty());
+        self.ranges.sort_by_key(|r| r.start);
+
+        let mut merged: Vec<Range<i64>> = Vec::with_capacity(self.ranges.len());
+        for range in self.ranges.drain(..) {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+
+        self.ranges = merged;
+    }
+}
+
+impl From<Range<i64>> for IntervalSet {
+    fn from(range: Range<i64>) -> Self {
+        Self::from_iter([range])
+    }
+}
+
+impl FromIterator<Range<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> Self {
+        let mut set = Self {
+            ranges: iter.into_iter().collect(),
+        };
+        set.normalize();
+        set
+    }
+}
+
+/// A piecewise mapping that adds an offset to the integers in each of its source ranges, and keeps all others unchanged.
+/// If source ranges overlap, the one added first applies.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OffsetTable {
+    entries: Vec<(Range<i64>, i64)>,
+}
+
+impl OffsetTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map the integers in `source` by adding `offset` to them.
+    pub fn push(&mut self, source: Range<i64>, offset: i64) {
+        self.entries.push((source, offset));
+    }
+
+    /// Map `length` integers starting at `source` to the ones starting at `destination`, as puzzles often describe mappings.
+    pub fn push_mapping(&mut self, destination: i64, source: i64, length: i64) {
+        self.push(source..source + length, destination - source);
+    }
+
+    pub fn map(&self, value: i64) -> i64 {
+        self.entries
+            .iter()
+            .find(|(source, _)| source.contains(&value))
+            .map_or(value, |(_, offset)| value + offset)
+    }
+
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        let mut remaining = set.clone();
+        let mut mapped = IntervalSet::new();
+
+        for (source, offset) in &self.entries {
+            let source = IntervalSet::from(source.clone());
+            mapped = mapped.union(&remaining.intersection(&source).shift(*offset));
+            remaining = remaining.difference(&source);
+        }
+
+        mapped.union(&remaining)
+    }
+}
+
+impl FromIterator<(Range<i64>, i64)> for OffsetTable {
+    fn from_iter<I: IntoIterator<Item = (Range<i64>, i64)>>(iter: I) -> Self {
+        Self {
+            entries: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// An axis-aligned box in `N` dimensions, e.g. a rectangle for `N = 2` or a cuboid for `N = 3`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Cuboid<const N: usize> {
+    pub ranges: [Range<i64>; N],
+}
+
+impl<const N: usize> Cuboid<N> {
+    pub fn new(ranges: [Range<i64>; N]) -> Self {
+        Self { ranges }
+    }
+
+    /// Number of integer points in the box.
+    pub fn volume(&self) -> i64 {
+        self.ranges
+            .iter()
+            .map(|r| (r.end - r.start).max(0))
+            .product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.iter().any(Range::is_empty)
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.ranges.iter().zip(point).all(|(r, p)| r.contains(&p))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let ranges = std::array::from_fn(|i| {
+            let (a, b) = (&self.ranges[i], &other.ranges[i]);
+            a.start.max(b.start)..a.end.min(b.end)
+        });
+
+        let intersection = Self { ranges };
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    /// Split the parts of this box outside of `other` into at most `2 * N` disjoint boxes.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let Some(overlap) = self.intersection(other) else {
+            return vec![self.clone()];
+        };
+
+        let mut pieces = vec![];
+        let mut rest = self.clone();
+
+        // cut off the slabs below and above the overlap, one axis at a time.
+        for axis in 0..N {
+            let inner = &overlap.ranges[axis];
+            let outer = rest.ranges[axis].clone();
+
+            for slab in [outer.start..inner.start, inner.end..outer.end] {
+                if !slab.is_empty() {
+                    let mut piece = rest.clone();
+                    piece.ranges[axis] = slab;
+                    pieces.push(piece);
+                }
+            }
+
+            rest.ranges[axis] = inner.clone();
+        }
+
+        pieces
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cuboid, IntervalSet, OffsetTable};
+
+    fn set(ranges: &[(i64, i64)]) -> IntervalSet {
+        ranges.iter().map(|&(start, end)| start..end).collect()
+    }
+
+    #[test]
+    fn normalizes_ranges() {
+        let set = set(&[(10, 15), (0, 3), (3, 5), (12, 20), (7, 7)]);
+        assert_eq!(
+            set.ranges().cloned().collect::<Vec<_>>(),
+            vec![0..5, 10..20]
+        );
+        assert_eq!(set.len(), 15);
+        assert_eq!(set.min(), Some(0));
+        assert_eq!(set.max(), Some(19));
+        assert!(set.contains(4) && set.contains(10) && !set.contains(5) && !set.contains(20));
+        assert!(IntervalSet::new().is_empty());
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 40)]);
+
+        assert_eq!(a.union(&b), set(&[(0, 40)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25), (28, 30)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 28)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20), (30, 40)]));
+        assert_eq!(
+            a.difference(&set(&[(2, 3), (4, 5), (21, 22)])),
+            set(&[(0, 2), (3, 4), (5, 10), (20, 21), (22, 30)])
+        );
+    }
+
+    #[test]
+    fn shifts_and_splits() {
+        let a = set(&[(0, 10), (20, 30)]);
+        assert_eq!(a.shift(-5), set(&[(-5, 5), (15, 25)]));
+
+        let (below, above) = a.split_at(25);
+        assert_eq!(below, set(&[(0, 10), (20, 25)]));
+        assert_eq!(above, set(&[(25, 30)]));
+    }
+
+    #[test]
+    fn maps_through_offset_table() {
+        // seed-to-soil map of 2023 day 5.
+        let mut table = OffsetTable::new();
+        table.push_mapping(50, 98, 2);
+        table.push_mapping(52, 50, 48);
+
+        assert_eq!(table.map(79), 81);
+        assert_eq!(table.map(98), 50);
+        assert_eq!(table.map(10), 10);
+
+        let seeds = set(&[(79, 93), (55, 68), (95, 105)]);
+        assert_eq!(
+            table.map_set(&seeds),
+            set(&[(50, 52), (57, 70), (81, 95), (97, 105)])
+        );
+    }
+
+    #[test]
+    fn calculates_with_cuboids() {
+        let a = Cuboid::new([0..3, 0..3, 0..3]);
+        let b = Cuboid::new([1..4, 1..4, 1..4]);
+
+        assert_eq!(a.volume(), 27);
+        assert!(a.contains([2, 0, 1]) && !a.contains([3, 0, 0]));
+        assert_eq!(a.intersection(&b), Some(Cuboid::new([1..3, 1..3, 1..3])));
+        assert_eq!(a.intersection(&Cuboid::new([3..4, 0..1, 0..1])), None);
+
+        let pieces = a.difference(&b);
+        assert_eq!(pieces.iter().map(Cuboid::volume).sum::<i64>(), 27 - 8);
+        for (i, piece) in pieces.iter().enumerate() {
+            assert!(piece.intersection(&b).is_none());
+            assert!(pieces[i + 1..]
+                .iter()
+                .all(|other| piece.intersection(other).is_none()));
+        }
+
+        let rectangle = Cuboid::new([0..4, 0..4]);
+        assert_eq!(rectangle.difference(&Cuboid::new([1..2, 1..2])).len(), 4);
+    }
+}
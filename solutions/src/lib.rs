@@ -1,4 +1,30 @@
+pub mod cycle;
+pub mod geometry;
+pub mod grid;
+pub mod interval;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod point;
+pub mod search;
+
 pub mod prelude {
+    pub use crate::cycle::{extrapolate, find_cycle, state_after, Cycle};
+    pub use crate::geometry::Polygon;
+    pub use crate::grid::{Grid, GridIndex};
+    pub use crate::interval::{Cuboid, IntervalSet, OffsetTable};
+    pub use crate::math::{
+        checked_product, checked_sum, crt, extended_gcd, gcd, gcd_all, isqrt, lcm, lcm_all,
+        mod_inv, mod_pow, Integer, Signed,
+    };
+    pub use crate::memo;
+    pub use crate::parse::{integers, parse_lines, sections, Field, LineError, ParseError};
+    pub use crate::point::{Direction, Point2, Point3};
+    pub use crate::scan;
+    pub use crate::search::{
+        astar, bfs, bfs_all, bidirectional_bfs, dfs, dijkstra, dijkstra_all, ShortestPaths,
+    };
+
     pub trait DebugInspect: Iterator {
         fn debug_inspect<F>(self, f: F) -> std::iter::Inspect<Self, impl FnMut(&Self::Item)>
             where
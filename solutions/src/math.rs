@@ -0,0 +1,329 @@
+//! Number theory for puzzles about cycles lining up, huge exponents or modular arithmetic.
+//!
+//! All functions are generic over the primitive integer types, so they work with whatever type a day uses.
+//! Intermediate results are computed with 128-bit integers, which is enough for moduli up to `2^64`.
+
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    iter::{Product, Sum},
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+/// The primitive integer types.
+pub trait Integer:
+    Copy
+    + Debug
+    + Default
+    + Hash
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Sum
+    + Product
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// The absolute value. Unsigned integers are returned unchanged.
+    fn abs(self) -> Self {
+        if self < Self::ZERO {
+            Self::ZERO - self
+        } else {
+            self
+        }
+    }
+
+    /// The largest integer whose square is at most `self`. Panics for negative numbers.
+    fn isqrt(self) -> Self;
+
+    /// Convert to the widest signed integer type, to compute intermediate results without overflowing.
+    /// Values of `u128` above `i128::MAX` are not supported.
+    fn widen(self) -> i128;
+
+    /// Convert back from [`Integer::widen`], or `None` if the value doesn't fit.
+    fn narrow(value: i128) -> Option<Self>;
+}
+
+/// The signed primitive integer types.
+pub trait Signed: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn isqrt(self) -> Self {
+                    <$t>::isqrt(self)
+                }
+
+                fn widen(self) -> i128 {
+                    self as i128
+                }
+
+                fn narrow(value: i128) -> Option<Self> {
+                    Self::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+
+/// Greatest common divisor, always non-negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (Integer::abs(a), Integer::abs(b));
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, always non-negative. It is 0 if either number is 0.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    Integer::abs(a / gcd(a, b) * b)
+}
+
+/// Greatest common divisor of all numbers, 0 for none.
+pub fn gcd_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ZERO, gcd)
+}
+
+/// Least common multiple of all numbers, 1 for none. Useful to find when several cycles line up.
+/// ```
+/// # use solutions::prelude::*;
+/// assert_eq!(lcm_all([4u64, 6, 10]), 60);
+/// ```
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ONE, lcm)
+}
+
+/// Extended Euclidean algorithm: returns `(g, x, y)` with `g = gcd(a, b)` and `a * x + b * y = g`.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The inverse of `a` modulo `modulus`, i.e. `x` in `0..modulus` with `a * x ≡ 1`. `None` if `a` and `modulus` are not coprime.
+pub fn mod_inv<T: Integer>(a: T, modulus: T) -> Option<T> {
+    let modulus = modulus.widen();
+    let (g, x, _) = extended_gcd(a.widen().rem_euclid(modulus), modulus);
+    if g != 1 {
+        return None;
+    }
+    T::narrow(x.rem_euclid(modulus))
+}
+
+/// `base` to the power of `exponent`, modulo `modulus`. The result is in `0..modulus`.
+/// ```
+/// # use solutions::prelude::*;
+/// assert_eq!(mod_pow(2u32, 10, 1000), 24);
+/// ```
+pub fn mod_pow<T: Integer>(base: T, exponent: T, modulus: T) -> T {
+    assert!(exponent >= T::ZERO, "exponent must not be negative.");
+
+    let modulus = modulus.widen();
+    let mut base = base.widen().rem_euclid(modulus);
+    let mut exponent = exponent.widen();
+    let mut result = 1 % modulus;
+
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent /= 2;
+    }
+
+    T::narrow(result).expect("result is smaller than the modulus.")
+}
+
+/// Chinese remainder theorem: the smallest non-negative `x` with `x ≡ residue (mod modulus)` for all pairs, and the period after which solutions repeat.
+/// Moduli don't need to be coprime. `None` if the congruences contradict each other or the result doesn't fit into `T`.
+/// ```
+/// # use solutions::prelude::*;
+/// assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+/// assert_eq!(crt([(1, 4), (2, 6)]), None);
+/// ```
+pub fn crt<T: Integer>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    let (mut result, mut period) = (0i128, 1i128);
+
+    for (residue, modulus) in congruences {
+        let modulus = modulus.widen();
+        let residue = residue.widen().rem_euclid(modulus);
+
+        let (g, p, _) = extended_gcd(period, modulus);
+        let difference = residue - result;
+        if difference % g != 0 {
+            return None;
+        }
+
+        // step from the current solution in multiples of the current period until the new congruence holds as well.
+        let reduced = modulus / g;
+        let steps = mul_mod(
+            (difference / g).rem_euclid(reduced),
+            p.rem_euclid(reduced),
+            reduced,
+        );
+        let next_period = period.checked_mul(reduced)?;
+
+        result = result
+            .checked_add(period.checked_mul(steps)?)?
+            .rem_euclid(next_period);
+        period = next_period;
+    }
+
+    Some((T::narrow(result)?, T::narrow(period)?))
+}
+
+/// The largest integer whose square is at most `n`. Panics for negative numbers.
+pub fn isqrt<T: Integer>(n: T) -> T {
+    n.isqrt()
+}
+
+/// Sum of all numbers, or `None` if it overflows.
+pub fn checked_sum<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers
+        .into_iter()
+        .try_fold(T::ZERO, |sum, n| sum.checked_add(n))
+}
+
+/// Product of all numbers, or `None` if it overflows.
+pub fn checked_product<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers
+        .into_iter()
+        .try_fold(T::ONE, |product, n| product.checked_mul(n))
+}
+
+/// `a * b % modulus` for `a` and `b` in `0..modulus`, without overflowing for moduli up to `2^64`.
+fn mul_mod(a: i128, b: i128, modulus: i128) -> i128 {
+    ((a as u128 * b as u128) % modulus as u128) as i128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        checked_product, checked_sum, crt, extended_gcd, gcd, gcd_all, isqrt, lcm, lcm_all,
+        mod_inv, mod_pow,
+    };
+
+    #[test]
+    fn calculates_gcd_and_lcm() {
+        assert_eq!(gcd(12u32, 18), 6);
+        assert_eq!(gcd(-12i64, 18), 6);
+        assert_eq!(gcd(0u8, 0), 0);
+        assert_eq!(lcm(4usize, 6), 12);
+        assert_eq!(lcm(-4i32, 6), 12);
+        assert_eq!(lcm(0i32, 6), 0);
+        assert_eq!(gcd_all([12u64, 18, 27]), 3);
+        assert_eq!(lcm_all(vec![2u64, 3, 4, 5]), 60);
+        assert_eq!(lcm_all(Vec::<u32>::new()), 1);
+    }
+
+    #[test]
+    fn calculates_bezout_coefficients() {
+        for (a, b) in [(240i64, 46), (-240, 46), (17, 5), (0, 7), (7, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn calculates_modular_arithmetic() {
+        assert_eq!(mod_inv(3u32, 11), Some(4));
+        assert_eq!(mod_inv(-3i64, 11), Some(7));
+        assert_eq!(mod_inv(4u32, 8), None);
+
+        assert_eq!(mod_pow(2u32, 10, 1000), 24);
+        assert_eq!(mod_pow(-2i64, 3, 5), 2);
+        assert_eq!(mod_pow(5u8, 0, 1), 0);
+        // Fermat's little theorem for the largest 64-bit prime.
+        let prime = u64::MAX - 58;
+        assert_eq!(mod_pow(3u64, prime - 1, prime), 1);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt([(2u64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1i32, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1u32, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1i64, 7), (0, 13)]), Some((13, 91)));
+        assert_eq!(crt(Vec::<(u8, u8)>::new()), Some((0, 1)));
+        // the period doesn't fit into a `u8`.
+        assert_eq!(crt([(0u8, 251), (1, 241)]), None);
+        // the period of three large 64-bit primes doesn't even fit into an `i128`.
+        assert_eq!(
+            crt([
+                (1u64, 18446744073709551557),
+                (2, 18446744073709551533),
+                (3, 18446744073709551521)
+            ]),
+            None
+        );
+    }
+
+    #[test]
+    fn calculates_square_roots() {
+        assert_eq!(isqrt(0u32), 0);
+        assert_eq!(isqrt(24u64), 4);
+        assert_eq!(isqrt(25i64), 5);
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    }
+
+    #[test]
+    fn detects_overflows() {
+        assert_eq!(checked_sum([1u8, 2, 3]), Some(6));
+        assert_eq!(checked_sum([200u8, 100]), None);
+        assert_eq!(checked_product([16u8, 15]), Some(240));
+        assert_eq!(checked_product([16u8, 16]), None);
+        assert_eq!(checked_sum([i64::MIN, -1]), None);
+    }
+}
@@ -0,0 +1,218 @@
+//! Caching for recursive functions, see [`memo!`](crate::memo!).
+//!
+//! Caches are scoped to a call tree: they are dropped as soon as the outermost memoized call returns.
+//! This way, repeated runs of a solution (e.g. while benchmarking) always start with an empty cache.
+//! To share a cache between several calls, run them in a [`scope`].
+
+use std::{
+    any::{Any, TypeId},
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    hash::Hash,
+};
+
+thread_local! {
+    /// Number of memoized calls and scopes that are currently running.
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+    /// The cache of every memoized function, by the type identifying the function.
+    static CACHES: RefCell<HashMap<TypeId, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// Define a function that caches its results, keyed by its arguments.
+/// Arguments must implement [`Hash`] and [`Eq`], borrowed ones are cloned into the key with [`ToOwned`], e.g. `&str` to a [`String`].
+/// The return type must implement [`Clone`].
+///
+/// Arguments marked with `#[shared]` are left out of the key. Use this for arguments that stay the same throughout
+/// a call tree, like the available towels of 2024 day 19, or ones that can't be owned by the cache.
+/// ```
+/// # use solutions::prelude::*;
+/// memo! {
+///     /// Number of stones a stone splits into after some blinks, see 2024 day 11.
+///     fn stones(stone: u64, blinks: u32) -> u64 {
+///         if blinks == 0 {
+///             return 1;
+///         }
+///         let digits = stone.checked_ilog10().unwrap_or(0) + 1;
+///         match stone {
+///             0 => stones(1, blinks - 1),
+///             _ if digits % 2 == 0 => {
+///                 let half = 10u64.pow(digits / 2);
+///                 stones(stone / half, blinks - 1) + stones(stone % half, blinks - 1)
+///             }
+///             _ => stones(stone * 2024, blinks - 1),
+///         }
+///     }
+/// }
+///
+/// // the cache is shared between all stones of the input.
+/// let total: u64 = memo::scope(|| [125, 17].into_iter().map(|stone| stones(stone, 75)).sum());
+/// assert_eq!(total, 65_601_038_650_482);
+/// ```
+#[macro_export]
+macro_rules! memo {
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($(#[$marker:ident])? $arg:ident : $ty:ty),* $(,)?) -> $ret:ty $body:block
+    ) => {
+        $(#[$attr])*
+        $vis fn $name($($arg: $ty),*) -> $ret {
+            struct __MemoCache;
+
+            fn __memo_compute($($arg: $ty),*) -> $ret $body
+
+            let _scope = $crate::memo::Scope::enter();
+            let key = ($($crate::memo!(@key $(#[$marker])? $arg),)*);
+
+            if let Some(value) = $crate::memo::get::<__MemoCache, _, $ret>(&key) {
+                return value;
+            }
+
+            let value = __memo_compute($($arg),*);
+            $crate::memo::insert::<__MemoCache, _, $ret>(key, value.clone());
+            value
+        }
+    };
+
+    (@key #[shared] $arg:ident) => {
+        ()
+    };
+    (@key $arg:ident) => {
+        $arg.to_owned()
+    };
+}
+
+/// Keep the caches of memoized functions until `f` returns, even between separate calls.
+pub fn scope<T>(f: impl FnOnce() -> T) -> T {
+    let _scope = Scope::enter();
+    f()
+}
+
+/// Keeps the caches alive while it exists. All caches are dropped when the last one is dropped.
+#[doc(hidden)]
+pub struct Scope(());
+
+impl Scope {
+    pub fn enter() -> Self {
+        DEPTH.set(DEPTH.get() + 1);
+        Self(())
+    }
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        DEPTH.set(DEPTH.get() - 1);
+        if DEPTH.get() == 0 {
+            // take the caches out first, so that dropped values can't observe the borrow.
+            let caches = CACHES.take();
+            drop(caches);
+        }
+    }
+}
+
+/// The cached result of the function identified by `F` for `key`.
+#[doc(hidden)]
+pub fn get<F, K, V>(key: &K) -> Option<V>
+where
+    F: 'static,
+    K: Hash + Eq + 'static,
+    V: Clone + 'static,
+{
+    CACHES.with_borrow(|caches| {
+        caches
+            .get(&TypeId::of::<F>())?
+            .downcast_ref::<HashMap<K, V>>()?
+            .get(key)
+            .cloned()
+    })
+}
+
+#[doc(hidden)]
+pub fn insert<F, K, V>(key: K, value: V)
+where
+    F: 'static,
+    K: Hash + Eq + 'static,
+    V: 'static,
+{
+    CACHES.with_borrow_mut(|caches| {
+        caches
+            .entry(TypeId::of::<F>())
+            .or_insert_with(|| Box::new(HashMap::<K, V>::new()))
+            .downcast_mut::<HashMap<K, V>>()
+            .expect("memoized functions have a fixed key and value type.")
+            .insert(key, value);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::scope;
+
+    thread_local! {
+        static CALLS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    memo! {
+        fn fibonacci(n: u64) -> u64 {
+            CALLS.set(CALLS.get() + 1);
+            if n < 2 {
+                n
+            } else {
+                fibonacci(n - 1) + fibonacci(n - 2)
+            }
+        }
+    }
+
+    memo! {
+        /// Number of ways to build `design` from the towels.
+        fn arrangements(design: &str, #[shared] towels: &[&str]) -> usize {
+            if design.is_empty() {
+                return 1;
+            }
+            towels
+                .iter()
+                .filter_map(|towel| design.strip_prefix(towel))
+                .map(|rest| arrangements(rest, towels))
+                .sum()
+        }
+    }
+
+    #[test]
+    fn caches_within_call_tree() {
+        CALLS.set(0);
+        assert_eq!(fibonacci(90), 2_880_067_194_370_816_120);
+        assert_eq!(CALLS.get(), 91);
+
+        // a new call tree starts with an empty cache.
+        assert_eq!(fibonacci(90), 2_880_067_194_370_816_120);
+        assert_eq!(CALLS.get(), 182);
+    }
+
+    #[test]
+    fn shares_cache_in_scope() {
+        CALLS.set(0);
+        scope(|| {
+            assert_eq!(fibonacci(50), 12_586_269_025);
+            assert_eq!(fibonacci(60), 1_548_008_755_920);
+        });
+        assert_eq!(CALLS.get(), 61);
+    }
+
+    #[test]
+    fn keys_borrowed_arguments() {
+        // example of 2024 day 19.
+        let towels = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"];
+        let designs = [
+            "brwrr", "bggr", "gbbr", "rrbgbr", "ubwu", "bwurrg", "brgr", "bbrwb",
+        ];
+        let counts: Vec<usize> = designs
+            .iter()
+            .map(|design| arrangements(design, &towels))
+            .collect();
+        assert_eq!(counts, vec![2, 1, 4, 6, 0, 1, 2, 0]);
+
+        // the shared towels are not part of the key, so other towels need a new call tree.
+        assert_eq!(arrangements("brwrr", &["b", "r", "wr"]), 1);
+    }
+}
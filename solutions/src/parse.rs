@@ -0,0 +1,519 @@
+//! Helpers to take puzzle inputs apart: typed fields from a line with [`scan!`](crate::scan), sections separated by blank lines and all integers in a line.
+
+use std::{
+    any::type_name,
+    fmt::{self, Display},
+    ops::Range,
+    str::FromStr,
+};
+
+/// An error while parsing a line, pointing at the column where it happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line that failed to parse. For errors about a [`Field`], this is the whole line the field is part of.
+    pub input: String,
+    /// Byte offset of the offending part of `input`.
+    pub offset: usize,
+    /// Column of the offending part of `input`, counting characters from 1.
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error at byte offset `offset` of `input`.
+    pub fn new(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        Self {
+            input: input.to_owned(),
+            offset,
+            column: input[..offset].chars().count() + 1,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at column {} of '{}'",
+            self.message, self.column, self.input
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// An error while parsing a line of the input, see [`parse_lines`].
+/// Its [`Display`] output shows the line with the offending column highlighted:
+/// ```text
+/// line 3, column 6: expected ': ' after id
+///   |
+/// 3 | Game 7; 1 red
+///   |      ^
+/// ```
+#[derive(Debug)]
+pub struct LineError {
+    /// Line number, counting from 1.
+    pub line: usize,
+    /// Column of the error, counting characters from 1. `None` if the parser didn't say where the error is.
+    pub column: Option<usize>,
+    pub text: String,
+    pub error: anyhow::Error,
+}
+
+impl LineError {
+    /// Wrap the error of parsing `text`, which is line `line` of the input.
+    /// The column is taken from a [`ParseError`] the error was caused by, if it is about `text`.
+    /// Errors about a copy of the line, or a part of it that was not scanned as a [`Field`], can't be located.
+    pub fn new(line: usize, text: &str, error: anyhow::Error) -> Self {
+        let column = error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<ParseError>())
+            .filter(|cause| cause.input == text)
+            .map(|cause| cause.column);
+
+        Self {
+            line,
+            column,
+            text: text.to_owned(),
+            error,
+        }
+    }
+}
+
+impl Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message: Vec<String> = self
+            .error
+            .chain()
+            .map(|cause| match cause.downcast_ref::<ParseError>() {
+                Some(cause) => cause.message.clone(),
+                None => cause.to_string(),
+            })
+            .collect();
+
+        let (padding, highlight) = match self.column {
+            Some(column) => (column - 1, 1),
+            None => (0, self.text.chars().count().max(1)),
+        };
+
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+
+        match self.column {
+            Some(column) => write!(f, "line {}, column {column}: ", self.line)?,
+            None => write!(f, "line {}: ", self.line)?,
+        }
+        writeln!(f, "{}", message.join(": "))?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{number} | {}", self.text)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(padding),
+            "^".repeat(highlight)
+        )
+    }
+}
+
+impl std::error::Error for LineError {}
+
+/// A part of a line matched by a placeholder of [`scan!`](crate::scan).
+/// It remembers where it is in the line, so that errors of scanning it again point into the whole line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Field<'a> {
+    pub name: &'static str,
+    pub value: &'a str,
+    line: &'a str,
+    offset: usize,
+}
+
+/// Text that can be scanned with [`scan!`](crate::scan): a line, or a [`Field`] of a line.
+#[doc(hidden)]
+pub trait Scannable<'a> {
+    /// The line the text is part of, and the byte range of the text in it.
+    fn span(&self) -> (&'a str, Range<usize>);
+}
+
+impl<'a, S: AsRef<str> + ?Sized> Scannable<'a> for &'a S {
+    fn span(&self) -> (&'a str, Range<usize>) {
+        let line = (*self).as_ref();
+        (line, 0..line.len())
+    }
+}
+
+impl<'a> Scannable<'a> for Field<'a> {
+    fn span(&self) -> (&'a str, Range<usize>) {
+        (self.line, self.offset..self.offset + self.value.len())
+    }
+}
+
+impl Field<'_> {
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.value.parse().map_err(|error| {
+            ParseError::new(
+                self.line,
+                self.offset,
+                format!("invalid {} '{}': {error}", self.name, self.value),
+            )
+        })
+    }
+}
+
+/// Extract typed fields from a line, matching the text around the `{}` placeholders of a pattern literally.
+/// Fields are named for error messages, and parsed with [`FromStr`] unless their type is `&str` or [`Field`](crate::parse::Field).
+/// A placeholder matches everything up to the first occurrence of the text following it.
+/// A `Field` can be scanned again, errors then still point into the whole line.
+/// ```
+/// # use solutions::prelude::*;
+/// let (id, reveals) = scan!("Game 12: 3 blue, 4 red", "Game {}: {}", id: u32, reveals: &str)?;
+/// assert_eq!((id, reveals), (12, "3 blue, 4 red"));
+///
+/// let error = scan!("Game x: 3 blue", "Game {}: {}", id: u32, reveals: &str).unwrap_err();
+/// assert_eq!(error.column, 6);
+///
+/// let (_, reveals) = scan!("Game 12: 300 red", "Game {}: {}", id: u32, reveals: Field)?;
+/// let error = scan!(reveals, "{} {}", count: u8, colour: &str).unwrap_err();
+/// assert_eq!(error.column, 10);
+/// # Ok::<(), ParseError>(())
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($input:expr, $pattern:literal, $($fields:tt)+) => {
+        $crate::scan!(@fields ($input, $pattern) [] $($fields)+)
+    };
+
+    (@fields $args:tt [$($done:tt)*] $name:ident : &str $(, $($rest:tt)*)?) => {
+        $crate::scan!(@fields $args [$($done)* ($name str)] $($($rest)*)?)
+    };
+    (@fields $args:tt [$($done:tt)*] $name:ident : Field $(, $($rest:tt)*)?) => {
+        $crate::scan!(@fields $args [$($done)* ($name field)] $($($rest)*)?)
+    };
+    (@fields $args:tt [$($done:tt)*] $name:ident : $t:ty $(, $($rest:tt)*)?) => {
+        $crate::scan!(@fields $args [$($done)* ($name parse $t)] $($($rest)*)?)
+    };
+
+    (@fields ($input:expr, $pattern:literal) [$(($name:ident $($kind:tt)+))+]) => {
+        $crate::parse::scan_fields($input, $pattern, &[$(stringify!($name)),+]).and_then(|fields| {
+            let mut fields = fields.into_iter();
+            $(
+                let field = fields.next().unwrap();
+                let $name = $crate::scan!(@value field, $($kind)+)?;
+            )+
+            Ok(($($name),+))
+        })
+    };
+
+    (@value $field:ident, str) => {
+        Ok::<_, $crate::parse::ParseError>($field.value)
+    };
+    (@value $field:ident, field) => {
+        Ok::<_, $crate::parse::ParseError>($field)
+    };
+    (@value $field:ident, parse $t:ty) => {
+        $field.parse::<$t>()
+    };
+}
+
+/// Split `line` at the placeholders of `pattern`, see [`scan!`](crate::scan).
+#[doc(hidden)]
+pub fn scan_fields<'a>(
+    input: impl Scannable<'a>,
+    pattern: &str,
+    names: &[&'static str],
+) -> Result<Vec<Field<'a>>, ParseError> {
+    let literals: Vec<&str> = pattern.split("{}").collect();
+    assert_eq!(
+        literals.len() - 1,
+        names.len(),
+        "pattern '{pattern}' needs one field per placeholder."
+    );
+
+    // offsets are relative to the whole line, so that errors point into it.
+    let (
+        line,
+        Range {
+            start,
+            end: line_end,
+        },
+    ) = input.span();
+
+    let expect = |offset: usize, literal: &str| {
+        if line[offset..line_end].starts_with(literal) {
+            Ok(offset + literal.len())
+        } else {
+            Err(ParseError::new(
+                line,
+                offset,
+                format!("expected '{literal}'"),
+            ))
+        }
+    };
+
+    let mut offset = expect(start, literals[0])?;
+    let mut fields = Vec::with_capacity(names.len());
+
+    for (&name, &next) in names.iter().zip(&literals[1..]) {
+        let end = if next.is_empty() {
+            assert!(
+                fields.len() + 1 == names.len(),
+                "placeholders in pattern '{pattern}' must be separated by text."
+            );
+            line_end
+        } else {
+            line[offset..line_end]
+                .find(next)
+                .map(|index| offset + index)
+                .ok_or_else(|| {
+                    ParseError::new(line, offset, format!("expected '{next}' after {name}"))
+                })?
+        };
+
+        fields.push(Field {
+            name,
+            value: &line[offset..end],
+            line,
+            offset,
+        });
+        offset = expect(end, next)?;
+    }
+
+    if offset < line_end {
+        return Err(ParseError::new(
+            line,
+            offset,
+            format!("unexpected '{}'", &line[offset..line_end]),
+        ));
+    }
+
+    Ok(fields)
+}
+
+/// Parse every line of the input with `parser`. Errors are annotated with the line they happened in.
+/// ```
+/// # use solutions::prelude::*;
+/// let numbers: Vec<u32> = parse_lines("1\n2\n3\n", str::parse).collect::<Result<_, _>>()?;
+/// assert_eq!(numbers, vec![1, 2, 3]);
+///
+/// let error = parse_lines("1\nx\n", str::parse::<u32>).nth(1).unwrap().unwrap_err();
+/// assert_eq!(error.line, 2);
+/// # Ok::<(), LineError>(())
+/// ```
+pub fn parse_lines<'a, T, E>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> Result<T, E> + 'a,
+) -> impl Iterator<Item = Result<T, LineError>> + 'a
+where
+    E: Into<anyhow::Error>,
+{
+    input.lines().enumerate().map(move |(index, line)| {
+        parser(line).map_err(|error| LineError::new(index + 1, line, error.into()))
+    })
+}
+
+/// Split the input into sections separated by blank lines, e.g. the rules and the updates of 2024 day 5.
+/// Sections don't include the trailing line break.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input;
+
+    std::iter::from_fn(move || {
+        rest = &rest[blank_prefix(rest)..];
+        if rest.is_empty() {
+            return None;
+        }
+
+        let end = rest
+            .split_inclusive('\n')
+            .take_while(|line| !line.trim().is_empty())
+            .map(str::len)
+            .sum();
+
+        let section = rest[..end].trim_end_matches(['\r', '\n']);
+        rest = &rest[end..];
+        Some(section)
+    })
+}
+
+/// All integers in a line, ignoring anything around them. A `-` directly in front of a number is its sign,
+/// unless it follows a letter or digit like in `3-7`.
+/// ```
+/// # use solutions::prelude::*;
+/// let numbers: Vec<i64> = integers("x=-3, y=12..-7, 2-5").collect::<Result<_, _>>()?;
+/// assert_eq!(numbers, vec![-3, 12, -7, 2, 5]);
+/// # Ok::<(), ParseError>(())
+/// ```
+pub fn integers<T>(line: &str) -> impl Iterator<Item = Result<T, ParseError>> + '_
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let bytes = line.as_bytes();
+    let mut offset = 0;
+
+    std::iter::from_fn(move || {
+        let start = offset + bytes[offset..].iter().position(u8::is_ascii_digit)?;
+        let end = start
+            + bytes[start..]
+                .iter()
+                .position(|b| !b.is_ascii_digit())
+                .unwrap_or(bytes.len() - start);
+        offset = end;
+
+        let is_negative = start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric());
+        let start = if is_negative { start - 1 } else { start };
+
+        let number = &line[start..end];
+        Some(number.parse().map_err(|error| {
+            ParseError::new(
+                line,
+                start,
+                format!("'{number}' is not a valid {}: {error}", type_name::<T>()),
+            )
+        }))
+    })
+}
+
+/// Length of the blank lines at the start of `input`.
+fn blank_prefix(input: &str) -> usize {
+    input
+        .split_inclusive('\n')
+        .take_while(|line| line.trim().is_empty())
+        .map(str::len)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+
+    use super::{integers, parse_lines, sections, ParseError};
+    use crate::point::Point2;
+
+    #[test]
+    fn scans_fields() {
+        let (id, reveals) =
+            scan!("Game 7: 1 red; 2 blue", "Game {}: {}", id: u32, reveals: &str).unwrap();
+        assert_eq!(id, 7);
+        assert_eq!(reveals, "1 red; 2 blue");
+
+        let (from, to) = scan!("0,9 -> 5,9", "{} -> {}", from: Point2, to: Point2).unwrap();
+        assert_eq!((from, to), (Point2::new(0, 9), Point2::new(5, 9)));
+
+        let count = scan!("42 units", "{} units", count: i64).unwrap();
+        assert_eq!(count, 42);
+    }
+
+    #[test]
+    fn reports_columns() {
+        let error = scan!("Game 7; 1 red", "Game {}: {}", id: u32, reveals: &str).unwrap_err();
+        assert_eq!(error.column, 6);
+        assert_eq!(
+            error.to_string(),
+            "expected ': ' after id at column 6 of 'Game 7; 1 red'"
+        );
+
+        let error = scan!("Round 7: 1 red", "Game {}: {}", id: u32, reveals: &str).unwrap_err();
+        assert_eq!(error.column, 1);
+
+        let scanned = scan!("Game 7: 1 red", "Game {}: {} red", id: u32, count: u8).unwrap();
+        assert_eq!(scanned, (7, 1));
+
+        let error = scan!("Game 7: 300 red", "Game {}: {} red", id: u32, count: u8).unwrap_err();
+        assert_eq!(error.column, 9);
+        assert!(error.message.starts_with("invalid count '300'"));
+
+        let error = scan!("42 units left", "{} units", count: i64).unwrap_err();
+        assert_eq!(error.message, "unexpected ' left'");
+        assert_eq!(error.column, 9);
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = "47|53\n97|13\n\n75,47\n\n\n \n61,13\n";
+        assert_eq!(
+            sections(input).collect::<Vec<_>>(),
+            vec!["47|53\n97|13", "75,47", "61,13"]
+        );
+        assert_eq!(
+            sections("\r\na\r\n\r\nb").collect::<Vec<_>>(),
+            vec!["a", "b"]
+        );
+        assert_eq!(sections("\n\n").count(), 0);
+    }
+
+    #[test]
+    fn extracts_integers() {
+        let numbers: Vec<i32> = integers("Sensor at x=2, y=-18: closest beacon is at x=-2, y=15")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(numbers, vec![2, -18, -2, 15]);
+
+        let numbers: Vec<u32> = integers("1-3 a: abc").collect::<Result<_, _>>().unwrap();
+        assert_eq!(numbers, vec![1, 3]);
+        assert_eq!(integers::<u8>("no numbers").count(), 0);
+
+        let error: ParseError = integers::<u8>("7 and 256").nth(1).unwrap().unwrap_err();
+        assert_eq!(error.column, 7);
+        assert!(error.message.starts_with("'256' is not a valid u8"));
+    }
+
+    #[test]
+    fn annotates_lines() {
+        let parse_game = |line| -> anyhow::Result<u32> {
+            let (id, reveals) = scan!(line, "Game {}: {}", id: u32, reveals: Field)?;
+            scan!(reveals, "{} {}", count: u8, colour: &str).context("invalid reveal")?;
+            Ok(id)
+        };
+
+        let input = "Game 1: 3 red\nGame 2: 1 blue\nGame 3; 2 red\n";
+        let error = parse_lines(input, parse_game).nth(2).unwrap().unwrap_err();
+        assert_eq!((error.line, error.column), (3, Some(6)));
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 6: expected ': ' after id\n  |\n3 | Game 3; 2 red\n  |      ^"
+        );
+
+        // errors in a part of the line point into the whole line.
+        let input = "Game 10: 300 red";
+        let error = parse_lines(input, parse_game).next().unwrap().unwrap_err();
+        assert_eq!(error.column, Some(10));
+        assert!(error
+            .to_string()
+            .starts_with("line 1, column 10: invalid reveal: invalid count '300'"));
+
+        // fields are located by position, even if their text appears earlier in the line.
+        let error = parse_lines("Game 300: 300 red", parse_game)
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error.column, Some(11));
+
+        // errors about a copy of the line can't be located.
+        let parse_copy = |line: &str| -> anyhow::Result<u32> {
+            let (id, _) = scan!(&line.to_lowercase(), "game {}: {}", id: u32, reveals: &str)?;
+            Ok(id)
+        };
+        let error = parse_lines("Game 3; 2 red", parse_copy)
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error.column, None);
+
+        // without a position, the whole line is highlighted.
+        let error = parse_lines("12\nab", str::parse::<u32>)
+            .nth(1)
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error.column, None);
+        assert_eq!(
+            error.to_string(),
+            "line 2: invalid digit found in string\n  |\n2 | ab\n  | ^^"
+        );
+    }
+}
@@ -0,0 +1,388 @@
+//! Points on integer grids, and the directions to move between them.
+//!
+//! Points double as vectors, e.g. the offset of a [`Direction`]. Like in [`Grid`](crate::grid::Grid), `x` grows to the right and `y` grows downwards.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use anyhow::{bail, Context};
+
+use crate::grid::GridIndex;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+macro_rules! impl_point {
+    ($point:ident { $($field:ident),+ }) => {
+        impl $point {
+            pub const ORIGIN: Self = Self { $($field: 0),+ };
+
+            pub const fn new($($field: i64),+) -> Self {
+                Self { $($field),+ }
+            }
+
+            /// Number of steps between two points when moving along the axes.
+            pub fn manhattan(self, other: Self) -> i64 {
+                0 $(+ (self.$field - other.$field).abs())+
+            }
+
+            /// Number of steps between two points when diagonal moves are allowed.
+            pub fn chebyshev(self, other: Self) -> i64 {
+                0_i64 $(.max((self.$field - other.$field).abs()))+
+            }
+        }
+
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = Self;
+
+            fn mul(self, factor: i64) -> Self {
+                Self { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl MulAssign<i64> for $point {
+            fn mul_assign(&mut self, factor: i64) {
+                *self = *self * factor;
+            }
+        }
+
+        /// Parses comma-separated coordinates, e.g. `3,-4`. Whitespace around coordinates is ignored.
+        impl FromStr for $point {
+            type Err = anyhow::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut coordinates = s.split(',').map(str::trim);
+
+                $(
+                    let $field = coordinates
+                        .next()
+                        .with_context(|| format!("point '{s}' is missing its {} coordinate", stringify!($field)))?
+                        .parse()
+                        .with_context(|| format!("invalid {} coordinate in point '{s}'", stringify!($field)))?;
+                )+
+
+                if coordinates.next().is_some() {
+                    bail!("point '{s}' has too many coordinates");
+                }
+
+                Ok(Self { $($field),+ })
+            }
+        }
+    };
+}
+
+impl_point!(Point2 { x, y });
+impl_point!(Point3 { x, y, z });
+
+impl Point2 {
+    /// Positions of the four points sharing an edge with this one, clockwise starting at the top.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+
+    /// Positions of the eight points sharing an edge or a corner with this one, clockwise starting at the top.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().flat_map(move |direction| {
+            let next = self + direction;
+            [next, next + direction.turn_right()]
+        })
+    }
+
+    /// The point reached after taking `steps` steps in a direction.
+    pub fn step(self, direction: Direction, steps: i64) -> Self {
+        self + direction.offset() * steps
+    }
+
+    /// Rotate the vector by 90 degrees, clockwise on screen.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotate the vector by 90 degrees, counter-clockwise on screen.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+impl Add<Direction> for Point2 {
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+}
+
+impl AddAssign<Direction> for Point2 {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+/// Positions returned by [`Grid`](crate::grid::Grid) convert into points.
+impl From<(usize, usize)> for Point2 {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x as i64, y as i64)
+    }
+}
+
+impl GridIndex for Point2 {
+    fn cell(self, width: usize, height: usize) -> Option<(usize, usize)> {
+        (self.x, self.y).cell(width, height)
+    }
+
+    fn wrapping_cell(self, width: usize, height: usize) -> (usize, usize) {
+        (self.x, self.y).wrapping_cell(width, height)
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// One of the four directions on a grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting at the top.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// The vector of a single step in this direction.
+    pub fn offset(self) -> Point2 {
+        match self {
+            Direction::Up => Point2::new(0, -1),
+            Direction::Right => Point2::new(1, 0),
+            Direction::Down => Point2::new(0, 1),
+            Direction::Left => Point2::new(-1, 0),
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        !self.is_vertical()
+    }
+}
+
+/// Accepts the notations puzzles use: `U`/`D`/`L`/`R`, `^`/`v`/`<`/`>` and `N`/`E`/`S`/`W`.
+impl TryFrom<char> for Direction {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | '^' | 'N' => Ok(Direction::Up),
+            'R' | '>' | 'E' => Ok(Direction::Right),
+            'D' | 'v' | 'S' => Ok(Direction::Down),
+            'L' | '<' | 'W' => Ok(Direction::Left),
+            _ => bail!("'{c}' is not a direction"),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.trim().chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => bail!("'{s}' is not a direction"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Point2, Point3};
+    use crate::grid::Grid;
+
+    #[test]
+    fn calculates_with_points() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(a - b, Point2::new(-3, 4));
+        assert_eq!(-a * 3, Point2::new(-3, -6));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        c *= 2;
+        assert_eq!(c, Point2::new(8, -4));
+
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::new(1, 1, 1),
+            Point3::new(2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn calculates_distances() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Point3::ORIGIN.manhattan(Point3::new(1, -2, 3)), 6);
+        assert_eq!(Point3::ORIGIN.chebyshev(Point3::new(1, -2, 3)), 3);
+    }
+
+    #[test]
+    fn parses_points() {
+        assert_eq!("3,-4".parse::<Point2>().unwrap(), Point2::new(3, -4));
+        assert_eq!("1, 0, 7".parse::<Point3>().unwrap(), Point3::new(1, 0, 7));
+        assert!("3".parse::<Point2>().is_err());
+        assert!("3,4,5".parse::<Point2>().is_err());
+        assert!("3,x".parse::<Point2>().is_err());
+        assert_eq!(Point2::new(3, -4).to_string(), "3,-4");
+    }
+
+    #[test]
+    fn turns_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(
+                direction.offset().rotate_right(),
+                direction.turn_right().offset()
+            );
+            assert_eq!(
+                direction.offset().rotate_left(),
+                direction.turn_left().offset()
+            );
+        }
+    }
+
+    #[test]
+    fn parses_directions() {
+        for (notation, direction) in [
+            ("URDL", Direction::Up),
+            ("^>v<", Direction::Up),
+            ("NESW", Direction::Up),
+        ] {
+            let parsed: Vec<Direction> = notation
+                .chars()
+                .map(|c| Direction::try_from(c).unwrap())
+                .collect();
+            assert_eq!(
+                parsed,
+                vec![
+                    direction,
+                    direction.turn_right(),
+                    direction.opposite(),
+                    direction.turn_left()
+                ]
+            );
+        }
+        assert_eq!(" R ".parse::<Direction>().unwrap(), Direction::Right);
+        assert!("X".parse::<Direction>().is_err());
+        assert!("UR".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn moves_on_grid() {
+        let grid = Grid::parse("#..\n.#.\n", |c| c).unwrap();
+        let start = Point2::from(grid.find(&'.').unwrap());
+        assert_eq!(start, Point2::new(1, 0));
+
+        assert_eq!(grid[start + Direction::Down], '#');
+        assert_eq!(grid.get(start + Direction::Up), None);
+        assert_eq!(grid.get_wrapping(start.step(Direction::Left, 2)), &'.');
+
+        let neighbours: Vec<Point2> = start.neighbours4().filter(|&p| grid.contains(p)).collect();
+        assert_eq!(
+            neighbours,
+            vec![Point2::new(2, 0), Point2::new(1, 1), Point2::new(0, 0)]
+        );
+        assert_eq!(start.neighbours8().count(), 8);
+    }
+}
@@ -0,0 +1,525 @@
+//! Graph searches, generic over the nodes and a function returning the successors of a node.
+//!
+//! Nodes can be anything hashable, e.g. grid positions or the states of a puzzle, so graphs never need to be built up front.
+//! Paths returned by the searches include both the start and the goal.
+//! ```
+//! # use solutions::prelude::*;
+//! let grid = Grid::parse("..#\n#..\n...\n", |c| c).unwrap();
+//! let successors = |p: &Point2| p.neighbours4().filter(|&n| grid.get(n) == Some(&'.'));
+//!
+//! let path = bfs(Point2::new(0, 0), successors, |p| *p == Point2::new(2, 2)).unwrap();
+//! assert_eq!(path.len() - 1, 4);
+//! ```
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Find a shortest path to a goal, where every step has the same cost.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(reconstruct(&parents, node));
+        }
+
+        for next in successors(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Find any path to a goal, exploring as deep as possible first. The path is not necessarily the shortest.
+pub fn dfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::from([(start.clone(), None)]);
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if is_goal(&node) {
+            return Some(reconstruct(&parents, node));
+        }
+
+        for next in successors(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                stack.push(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Find a cheapest path to a goal. Successors are returned with the cost of the step to them, which must not be negative.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Find a cheapest path to a goal, guided by a heuristic estimating the remaining cost from a node.
+/// The path is only guaranteed to be the cheapest if the heuristic never overestimates, e.g. the Manhattan distance on a grid.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // nodes are not necessarily `Ord`, so the queue refers to them by their index in `nodes`.
+    let mut nodes = vec![start.clone()];
+    let mut queue = BinaryHeap::from([(Reverse(heuristic(&start)), Reverse(C::default()), 0)]);
+    let mut best: HashMap<N, (C, Option<N>)> = HashMap::from([(start, (C::default(), None))]);
+
+    while let Some((_, Reverse(cost), index)) = queue.pop() {
+        let node = nodes[index].clone();
+
+        // skip outdated entries of nodes that were reached more cheaply after being queued.
+        if best
+            .get(&node)
+            .is_some_and(|(best_cost, _)| cost > *best_cost)
+        {
+            continue;
+        }
+
+        if is_goal(&node) {
+            let parents: HashMap<N, Option<N>> = best
+                .into_iter()
+                .map(|(node, (_, parent))| (node, parent))
+                .collect();
+            return Some((reconstruct(&parents, node), cost));
+        }
+
+        for (next, step_cost) in successors(&node) {
+            let next_cost = cost + step_cost;
+
+            if best
+                .get(&next)
+                .is_none_or(|(best_cost, _)| next_cost < *best_cost)
+            {
+                best.insert(next.clone(), (next_cost, Some(node.clone())));
+                queue.push((
+                    Reverse(next_cost + heuristic(&next)),
+                    Reverse(next_cost),
+                    nodes.len(),
+                ));
+                nodes.push(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Find a shortest path between two nodes by searching from both ends, which visits far fewer nodes in large graphs.
+/// Edges must go both ways, i.e. `b` is a successor of `a` if and only if `a` is a successor of `b`.
+pub fn bidirectional_bfs<N, I>(start: N, goal: N, successors: impl Fn(&N) -> I) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    if start == goal {
+        return Some(vec![start]);
+    }
+
+    let mut forward: HashMap<N, (usize, Option<N>)> = HashMap::from([(start.clone(), (0, None))]);
+    let mut backward: HashMap<N, (usize, Option<N>)> = HashMap::from([(goal.clone(), (0, None))]);
+    let mut forward_frontier = vec![start];
+    let mut backward_frontier = vec![goal];
+
+    while !forward_frontier.is_empty() && !backward_frontier.is_empty() {
+        // expand the smaller frontier by a full layer, so that the first meeting yields a shortest path.
+        let is_forward = forward_frontier.len() <= backward_frontier.len();
+        let (frontier, visited, other) = if is_forward {
+            (&mut forward_frontier, &mut forward, &backward)
+        } else {
+            (&mut backward_frontier, &mut backward, &forward)
+        };
+
+        let mut next_frontier = vec![];
+        let mut meeting: Option<(usize, N)> = None;
+
+        for node in frontier.drain(..) {
+            let distance = visited[&node].0 + 1;
+
+            for next in successors(&node) {
+                if visited.contains_key(&next) {
+                    continue;
+                }
+
+                visited.insert(next.clone(), (distance, Some(node.clone())));
+
+                if let Some((other_distance, _)) = other.get(&next) {
+                    if meeting
+                        .as_ref()
+                        .is_none_or(|(best, _)| other_distance < best)
+                    {
+                        meeting = Some((*other_distance, next.clone()));
+                    }
+                }
+
+                next_frontier.push(next);
+            }
+        }
+
+        if let Some((_, node)) = meeting {
+            let to_parent =
+                |(node, (_, parent)): (&N, &(usize, Option<N>))| (node.clone(), parent.clone());
+            let forward: HashMap<N, Option<N>> = forward.iter().map(to_parent).collect();
+            let backward: HashMap<N, Option<N>> = backward.iter().map(to_parent).collect();
+
+            let mut path = reconstruct(&forward, node.clone());
+            let mut rest = reconstruct(&backward, node);
+            rest.reverse();
+            path.extend(rest.into_iter().skip(1));
+            return Some(path);
+        }
+
+        *frontier = next_frontier;
+    }
+
+    None
+}
+
+/// Distances and predecessors of all nodes reachable from a start node, for questions about all shortest paths.
+pub struct ShortestPaths<N, C> {
+    nodes: HashMap<N, (C, Vec<N>)>,
+}
+
+/// Find the shortest paths to all reachable nodes, where every step has the same cost.
+pub fn bfs_all<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> ShortestPaths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut nodes: HashMap<N, (usize, Vec<N>)> = HashMap::from([(start.clone(), (0, vec![]))]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = nodes[&node].0 + 1;
+
+        for next in successors(&node) {
+            match nodes.get_mut(&next) {
+                None => {
+                    nodes.insert(next.clone(), (distance, vec![node.clone()]));
+                    queue.push_back(next);
+                }
+                Some((next_distance, parents)) if *next_distance == distance => {
+                    parents.push(node.clone());
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    ShortestPaths { nodes }
+}
+
+/// Find the cheapest paths to all reachable nodes. Step costs must be positive.
+pub fn dijkstra_all<N, C, I>(start: N, mut successors: impl FnMut(&N) -> I) -> ShortestPaths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut queued = vec![start.clone()];
+    let mut queue = BinaryHeap::from([(Reverse(C::default()), 0)]);
+    let mut nodes: HashMap<N, (C, Vec<N>)> = HashMap::from([(start, (C::default(), vec![]))]);
+
+    while let Some((Reverse(cost), index)) = queue.pop() {
+        let node = queued[index].clone();
+
+        if nodes[&node].0 < cost {
+            continue;
+        }
+
+        for (next, step_cost) in successors(&node) {
+            let next_cost = cost + step_cost;
+
+            match nodes.get_mut(&next) {
+                Some((best_cost, parents)) if next_cost == *best_cost => {
+                    parents.push(node.clone());
+                }
+                Some((best_cost, _)) if next_cost > *best_cost => {}
+                _ => {
+                    nodes.insert(next.clone(), (next_cost, vec![node.clone()]));
+                    queue.push((Reverse(next_cost), queued.len()));
+                    queued.push(next);
+                }
+            }
+        }
+    }
+
+    ShortestPaths { nodes }
+}
+
+impl<N, C> ShortestPaths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord,
+{
+    /// Cost of the shortest paths to a node, or `None` if it can't be reached.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.nodes.get(node).map(|(cost, _)| *cost)
+    }
+
+    /// All reachable nodes with the cost of the shortest paths to them.
+    pub fn costs(&self) -> impl Iterator<Item = (&N, C)> {
+        self.nodes.iter().map(|(node, (cost, _))| (node, *cost))
+    }
+
+    /// One of the shortest paths to a node.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.nodes.get(node)?;
+
+        let parents: HashMap<N, Option<N>> = self
+            .nodes
+            .iter()
+            .map(|(node, (_, parents))| (node.clone(), parents.first().cloned()))
+            .collect();
+
+        Some(reconstruct(&parents, node.clone()))
+    }
+
+    /// Number of distinct shortest paths to a node.
+    pub fn count_paths(&self, node: &N) -> u64 {
+        // predecessors are always cheaper, so counting nodes by ascending cost only ever uses final counts.
+        let mut relevant: Vec<&N> = self.nodes_on_paths(node).into_iter().collect();
+        relevant.sort_by_key(|node| self.nodes[*node].0);
+
+        let mut counts: HashMap<&N, u64> = HashMap::new();
+        for current in relevant {
+            let parents = &self.nodes[current].1;
+            let count = if parents.is_empty() {
+                1
+            } else {
+                parents.iter().map(|parent| counts[parent]).sum()
+            };
+            counts.insert(current, count);
+        }
+
+        counts.get(node).copied().unwrap_or_default()
+    }
+
+    /// All nodes that lie on at least one of the shortest paths to a node, including the start and the node itself.
+    pub fn nodes_on_paths(&self, node: &N) -> HashSet<&N> {
+        let mut seen = HashSet::new();
+        let Some((node, _)) = self.nodes.get_key_value(node) else {
+            return seen;
+        };
+
+        let mut stack = vec![node];
+        while let Some(current) = stack.pop() {
+            if seen.insert(current) {
+                stack.extend(self.nodes[current].1.iter());
+            }
+        }
+
+        seen
+    }
+}
+
+/// Follow the parents from a node back to the start, returning the path from the start to the node.
+fn reconstruct<N: Clone + Eq + Hash>(parents: &HashMap<N, Option<N>>, node: N) -> Vec<N> {
+    let mut path = vec![node];
+
+    while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, bfs_all, bidirectional_bfs, dfs, dijkstra, dijkstra_all};
+    use crate::{
+        grid::Grid,
+        point::{Direction, Point2},
+    };
+
+    const MAZE: &str = "\
+...#....
+.#.#.##.
+.#...#..
+.####.#.
+......#.
+";
+
+    fn maze() -> Grid<char> {
+        Grid::parse(MAZE, |c| c).unwrap()
+    }
+
+    fn open_neighbours(grid: &Grid<char>) -> impl Fn(&Point2) -> Vec<Point2> + '_ {
+        |p| {
+            p.neighbours4()
+                .filter(|&n| grid.get(n) == Some(&'.'))
+                .collect()
+        }
+    }
+
+    fn is_valid_path(grid: &Grid<char>, path: &[Point2]) -> bool {
+        path.windows(2)
+            .all(|w| w[0].manhattan(w[1]) == 1 && grid[w[1]] == '.')
+    }
+
+    #[test]
+    fn finds_shortest_path_with_bfs() {
+        let grid = maze();
+        let goal = Point2::new(7, 4);
+        let path = bfs(Point2::ORIGIN, open_neighbours(&grid), |p| *p == goal).unwrap();
+
+        assert_eq!(path.first(), Some(&Point2::ORIGIN));
+        assert_eq!(path.last(), Some(&goal));
+        assert_eq!(path.len() - 1, 15);
+        assert!(is_valid_path(&grid, &path));
+
+        assert!(bfs(Point2::ORIGIN, open_neighbours(&grid), |p| p.x > 10).is_none());
+    }
+
+    #[test]
+    fn finds_any_path_with_dfs() {
+        let grid = maze();
+        let goal = Point2::new(7, 4);
+        let path = dfs(Point2::ORIGIN, open_neighbours(&grid), |p| *p == goal).unwrap();
+
+        assert_eq!(path.last(), Some(&goal));
+        assert!(path.len() > 15);
+        assert!(is_valid_path(&grid, &path));
+    }
+
+    #[test]
+    fn finds_cheapest_path() {
+        // turning costs 1000, like in a reindeer maze.
+        let grid = maze();
+        let successors = |&(p, d): &(Point2, Direction)| {
+            let mut next = vec![((p, d.turn_left()), 1000), ((p, d.turn_right()), 1000)];
+            if grid.get(p + d) == Some(&'.') {
+                next.push(((p + d, d), 1));
+            }
+            next
+        };
+        let goal = Point2::new(7, 4);
+        let start = (Point2::ORIGIN, Direction::Right);
+
+        let (path, cost) = dijkstra(start, successors, |(p, _)| *p == goal).unwrap();
+        assert_eq!(cost, 5015);
+        assert_eq!(path.last().unwrap().0, goal);
+
+        let (_, astar_cost) = astar(
+            start,
+            successors,
+            |(p, _)| p.manhattan(goal),
+            |(p, _)| *p == goal,
+        )
+        .unwrap();
+        assert_eq!(astar_cost, cost);
+    }
+
+    #[test]
+    fn finds_shortest_path_from_both_ends() {
+        let grid = maze();
+        let goal = Point2::new(7, 4);
+        let path = bidirectional_bfs(Point2::ORIGIN, goal, open_neighbours(&grid)).unwrap();
+
+        assert_eq!(path.first(), Some(&Point2::ORIGIN));
+        assert_eq!(path.last(), Some(&goal));
+        assert_eq!(path.len() - 1, 15);
+        assert!(is_valid_path(&grid, &path));
+
+        assert_eq!(
+            bidirectional_bfs(goal, goal, open_neighbours(&grid)),
+            Some(vec![goal])
+        );
+        assert!(
+            bidirectional_bfs(Point2::ORIGIN, Point2::new(20, 20), open_neighbours(&grid))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn searches_implicit_state_spaces() {
+        // reach 10 from 1 by doubling or adding one.
+        let path = bfs(1, |&n: &u32| [n * 2, n + 1], |&n| n == 10).unwrap();
+        assert_eq!(path, vec![1, 2, 4, 5, 10]);
+    }
+
+    #[test]
+    fn counts_shortest_paths() {
+        let grid = Grid::new(3, 3, '.');
+        let paths = bfs_all(Point2::ORIGIN, open_neighbours(&grid));
+        let corner = Point2::new(2, 2);
+
+        assert_eq!(paths.cost(&corner), Some(4));
+        assert_eq!(paths.count_paths(&corner), 6);
+        assert_eq!(paths.count_paths(&Point2::new(1, 0)), 1);
+        assert_eq!(paths.count_paths(&Point2::new(5, 5)), 0);
+        assert_eq!(paths.nodes_on_paths(&corner).len(), 9);
+        assert_eq!(paths.nodes_on_paths(&Point2::new(0, 2)).len(), 3);
+        assert_eq!(paths.path(&corner).unwrap().len(), 5);
+        assert_eq!(paths.costs().count(), 9);
+    }
+
+    #[test]
+    fn counts_cheapest_paths() {
+        // two routes of cost 3 and one of cost 4 from 'a' to 'd'.
+        let edges = [
+            ('a', 'b', 1),
+            ('b', 'd', 2),
+            ('a', 'c', 2),
+            ('c', 'd', 1),
+            ('a', 'd', 4),
+        ];
+        let successors = |node: &char| {
+            edges
+                .iter()
+                .filter(|(from, _, _)| from == node)
+                .map(|&(_, to, cost)| (to, cost))
+                .collect::<Vec<_>>()
+        };
+
+        let paths = dijkstra_all('a', successors);
+        assert_eq!(paths.cost(&'d'), Some(3));
+        assert_eq!(paths.count_paths(&'d'), 2);
+        assert_eq!(paths.nodes_on_paths(&'d').len(), 4);
+        assert_eq!(paths.path(&'a'), Some(vec!['a']));
+    }
+}
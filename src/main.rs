@@ -1,4 +1,10 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::bench_env::BenchOptions;
+#[cfg(feature = "profile")]
+use advent_of_code::template::commands::profile;
+use advent_of_code::template::commands::{
+    all, crosscheck, doctor, download, inputs, leaderboard, read, report, scaffold, solve, stars,
+    time, unscaffold,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
@@ -7,8 +13,8 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{commands::inputs, runner::InputSource, Day};
+    use std::{path::PathBuf, process};
 
     pub enum AppArguments {
         Download {
@@ -20,20 +26,53 @@ mod args {
         Scaffold {
             day: Day,
             download: bool,
+            template: Option<String>,
+        },
+        Unscaffold {
+            day: Day,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            input: InputSource,
         },
         All {
             release: bool,
         },
+        Crosscheck {
+            day: Option<Day>,
+            release: bool,
+        },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            chart: bool,
+            pin: Option<usize>,
+            high_priority: bool,
+        },
+        Report {
+            html: Option<PathBuf>,
+        },
+        Stars {
+            leaderboard: bool,
+            file: Option<PathBuf>,
+            user: Option<u64>,
+        },
+        Leaderboard {
+            id: Option<u64>,
+            file: Option<PathBuf>,
+        },
+        Doctor,
+        Inputs {
+            action: inputs::Action,
+        },
+        #[cfg(feature = "profile")]
+        Profile {
+            day: Day,
+            part: Option<u8>,
         },
         #[cfg(feature = "today")]
         Today,
@@ -46,14 +85,44 @@ mod args {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
             },
+            Some("crosscheck") => {
+                // flags first, otherwise `--release` would be taken for the optional day.
+                let release = args.contains("--release");
+                AppArguments::Crosscheck {
+                    day: args.opt_free_from_str()?,
+                    release,
+                }
+            }
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let chart = args.contains("--chart");
+                let pin = args.opt_value_from_str("--pin")?;
+                let high_priority = args.contains("--high-priority");
 
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    chart,
+                    pin,
+                    high_priority,
+                }
+            }
+            Some("report") => AppArguments::Report {
+                html: args.opt_value_from_str("--html")?,
+            },
+            Some("stars") => AppArguments::Stars {
+                leaderboard: args.contains("--leaderboard"),
+                file: args.opt_value_from_str("--file")?,
+                user: args.opt_value_from_str("--user")?,
+            },
+            Some("leaderboard") => {
+                let file = args.opt_value_from_str("--file")?;
+
+                AppArguments::Leaderboard {
+                    id: args.opt_free_from_str()?,
+                    file,
                 }
             }
             Some("download") => AppArguments::Download {
@@ -65,12 +134,44 @@ mod args {
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
+                template: args.opt_value_from_str("--template")?,
             },
-            Some("solve") => AppArguments::Solve {
+            Some("unscaffold") => AppArguments::Unscaffold {
                 day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+            },
+            Some("solve") => {
+                let example = args.opt_value_from_str("--example")?;
+                let file = args.opt_value_from_str("--input")?;
+                let stdin = args.contains("--stdin");
+
+                let input = match (example, file, stdin) {
+                    (None, None, false) => InputSource::Puzzle,
+                    (Some(part), None, false) => InputSource::Example(part),
+                    (None, Some(path), false) => InputSource::File(path),
+                    (None, None, true) => InputSource::Stdin,
+                    _ => {
+                        return Err(
+                            "only one of `--example`, `--input` and `--stdin` can be used.".into(),
+                        )
+                    }
+                };
+
+                AppArguments::Solve {
+                    day: args.free_from_str()?,
+                    release: args.contains("--release"),
+                    submit: args.opt_value_from_str("--submit")?,
+                    dhat: args.contains("--dhat"),
+                    input,
+                }
+            }
+            Some("doctor") => AppArguments::Doctor,
+            Some("inputs") => AppArguments::Inputs {
+                action: args.free_from_str()?,
+            },
+            #[cfg(feature = "profile")]
+            Some("profile") => AppArguments::Profile {
+                day: args.free_from_str()?,
+                part: args.opt_value_from_str("--part")?,
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -101,27 +202,62 @@ fn main() {
         }
         Ok(args) => match args {
             AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::Crosscheck { day, release } => crosscheck::handle(day, release),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                chart,
+                pin,
+                high_priority,
+            } => time::handle(
+                day,
+                all,
+                store,
+                chart,
+                BenchOptions {
+                    pin_cpu: pin,
+                    high_priority,
+                },
+            ),
+            AppArguments::Report { html } => report::handle(html),
+            AppArguments::Stars {
+                leaderboard,
+                file,
+                user,
+            } => stars::handle(leaderboard, file, user),
+            AppArguments::Leaderboard { id, file } => leaderboard::handle(id, file),
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day, download } => {
-                scaffold::handle(day);
+            AppArguments::Scaffold {
+                day,
+                download,
+                template,
+            } => {
+                // download first, so the puzzle description is available to the template.
                 if download {
                     download::handle(day);
                 }
+                scaffold::handle(day, template.as_deref());
             }
+            AppArguments::Unscaffold { day } => unscaffold::handle(day),
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                input,
+            } => solve::handle(day, release, dhat, submit, &input),
+            AppArguments::Doctor => doctor::handle(),
+            AppArguments::Inputs { action } => inputs::handle(&action),
+            #[cfg(feature = "profile")]
+            AppArguments::Profile { day, part } => profile::handle(day, part),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day);
                         download::handle(day);
+                        scaffold::handle(day, None);
                         read::handle(day)
                     }
                     None => {
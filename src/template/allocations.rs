@@ -0,0 +1,110 @@
+/// Module that reads the heap profiles written by `solve --dhat`, so that reports can show allocations next to timings.
+use std::{collections::HashMap, fs, path::PathBuf, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{workspace, DataFile, Day};
+
+/// Heap usage of a single part, summed up over all allocation sites.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Allocations {
+    pub total_bytes: u64,
+    pub total_blocks: u64,
+    /// Bytes allocated at the point of maximum heap usage.
+    pub peak_bytes: u64,
+}
+
+impl Allocations {
+    /// Read the heap profile of a part. Returns `None` if the part was not profiled yet.
+    pub fn read(day: Day, part: u8) -> Option<Self> {
+        let path = profile_path(DataFile::DayPart(day, part));
+        let s = fs::read_to_string(&path).ok()?;
+
+        match Allocations::try_from(s) {
+            Ok(allocations) => Some(allocations),
+            Err(e) => {
+                eprintln!("Ignoring heap profile \"{}\": {e}", path.display());
+                None
+            }
+        }
+    }
+}
+
+/// The absolute path of the heap profile of a part, e.g. `data/profiles/01-1.dhat.json`.
+pub fn profile_path(file: DataFile) -> PathBuf {
+    workspace::absolute(workspace::profiles_dir().join(file.as_path("dhat.json")))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Parses the JSON format written by the `dhat` crate. Only the totals of its program points (`pps`) are used.
+impl TryFrom<String> for Allocations {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let program_points = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("pps")
+            .and_then(|pps| pps.get::<Vec<JsonValue>>())
+            .ok_or("expected `json.pps` to be an array.")?;
+
+        let mut allocations = Allocations::default();
+
+        for point in program_points {
+            let point = point
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("expected program point to be an object.")?;
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let field = |key: &str| {
+                point
+                    .get(key)
+                    .and_then(|v| v.get::<f64>())
+                    .map_or(0, |v| *v as u64)
+            };
+
+            allocations.total_bytes += field("tb");
+            allocations.total_blocks += field("tbk");
+            allocations.peak_bytes += field("gb");
+        }
+
+        Ok(allocations)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Allocations;
+
+    #[test]
+    fn sums_program_points() {
+        let json = r#"{
+            "dhatFileVersion": 2, "mode": "rust-heap", "verb": "Allocated", "bklt": true, "bkacc": false,
+            "tu": "µs", "Mtu": "s", "tuth": 10, "cmd": "target/dhat/01", "pid": 1, "tg": 10, "te": 20,
+            "pps": [
+                { "tb": 1024, "tbk": 2, "tl": 5, "mb": 1024, "mbk": 2, "gb": 512, "gbk": 1, "eb": 0, "ebk": 0, "fs": [1] },
+                { "tb": 100, "tbk": 10, "tl": 5, "mb": 10, "mbk": 1, "gb": 10, "gbk": 1, "eb": 0, "ebk": 0, "fs": [2] }
+            ],
+            "ftbl": ["[root]", "a", "b"]
+        }"#;
+
+        assert_eq!(
+            Allocations::try_from(json.to_string()).unwrap(),
+            Allocations {
+                total_bytes: 1124,
+                total_blocks: 12,
+                peak_bytes: 522,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_profiles() {
+        assert!(Allocations::try_from("[]".to_string()).is_err());
+        assert!(Allocations::try_from("{}".to_string()).is_err());
+    }
+}
@@ -2,6 +2,8 @@
 use std::{
     ffi::OsString,
     fmt::Display,
+    fs,
+    path::PathBuf,
     process::{Command, Output, Stdio},
 };
 
@@ -83,16 +85,63 @@ pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandErro
     let args = build_args("submit", std::iter::empty(), day)
         .chain([part.to_string().into(), result.to_string().into()]);
 
-    call_aoc_cli(args)
+    // capture the response, so that it can be checked with `submission_verdict`.
+    let output = call_aoc_cli_with_stdout(args, Stdio::piped())?;
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+    Ok(output)
+}
+
+/// Interpret the response to a submission. Returns `None` if the answer was neither accepted nor rejected,
+/// e.g. because of rate limiting or because the part was already solved.
+pub fn submission_verdict(output: &Output) -> Option<bool> {
+    let response = String::from_utf8_lossy(&output.stdout);
+
+    if response.contains("That's the right answer") {
+        Some(true)
+    } else if response.contains("That's not the right answer") {
+        Some(false)
+    } else {
+        None
+    }
 }
 
-fn get_year() -> Option<u16> {
+pub fn get_year() -> Option<u16> {
     match std::env::var("AOC_YEAR") {
         Ok(x) => x.parse().ok().or(None),
         Err(_) => None,
     }
 }
 
+/// Reads the user id from `AOC_USER_ID`.
+/// The id of a private leaderboard is the user id of its owner, so this doubles as the default leaderboard id.
+pub fn get_user_id() -> Option<u64> {
+    std::env::var("AOC_USER_ID").ok()?.parse().ok()
+}
+
+/// Reads the session cookie from the same locations as aoc-cli.
+pub fn read_session() -> Result<String, String> {
+    if let Ok(session) = std::env::var("ADVENT_OF_CODE_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| home.as_ref().map(|home| home.join(".config")));
+
+    [
+        home.map(|home| home.join(".adventofcode.session")),
+        config.map(|config| config.join("adventofcode.session")),
+    ]
+    .into_iter()
+    .flatten()
+    .find_map(|path| fs::read_to_string(path).ok())
+    .map(|session| session.trim().to_string())
+    .ok_or_else(|| {
+        "could not find session cookie. See the readme on how to configure aoc-cli.".into()
+    })
+}
+
 fn build_args(
     command: &str,
     args: impl Iterator<Item = OsString>,
@@ -115,10 +164,17 @@ fn build_args(
 }
 
 fn call_aoc_cli(args: impl Iterator<Item = OsString>) -> Result<Output, AocCommandError> {
+    call_aoc_cli_with_stdout(args, Stdio::inherit())
+}
+
+fn call_aoc_cli_with_stdout(
+    args: impl Iterator<Item = OsString>,
+    stdout: Stdio,
+) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
     let output = Command::new("aoc")
         .args(args)
-        .stdout(Stdio::inherit())
+        .stdout(stdout)
         .stderr(Stdio::inherit())
         .output()
         .map_err(|_| AocCommandError::CommandNotCallable)?;
@@ -0,0 +1,269 @@
+/// Module that controls and inspects the scheduling environment benchmarks run in.
+///
+/// Options are applied to the runner process itself, so every child spawned by `run_multi`
+/// (and, transitively, the solution binary spawned by `cargo run`) inherits them.
+use std::{collections::HashMap, fs};
+
+use tinyjson::JsonValue;
+
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+/// Niceness requested when `--high-priority` is passed. Lower values mean higher priority.
+const HIGH_PRIORITY_NICENESS: i32 = -10;
+
+/// 1-minute load average above which we warn about concurrent load.
+const LOAD_WARNING_THRESHOLD: f64 = 1.0;
+
+/// The governor that keeps the CPU at a stable, maximum frequency.
+const STABLE_GOVERNOR: &str = "performance";
+
+/// Scheduling options requested on the command-line.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BenchOptions {
+    pub pin_cpu: Option<usize>,
+    pub high_priority: bool,
+}
+
+/// The conditions a benchmark was actually run under.
+/// Stored alongside each timing so that results from noisy runs can be told apart.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BenchConditions {
+    pub pinned_cpu: Option<usize>,
+    pub niceness: Option<i32>,
+    pub governor: Option<String>,
+    pub load_average: Option<f64>,
+}
+
+/// Apply `options` to the current process, inspect the system and print warnings for anything
+/// that is likely to skew results. Returns the conditions that are in effect.
+pub fn prepare(options: BenchOptions) -> BenchConditions {
+    let pinned_cpu = options.pin_cpu.and_then(|cpu| match sys::pin_to_cpu(cpu) {
+        Ok(()) => Some(cpu),
+        Err(e) => {
+            eprintln!("Warning: could not pin benchmarks to CPU {cpu}: {e}");
+            None
+        }
+    });
+
+    if options.high_priority {
+        if let Err(e) = sys::set_niceness(HIGH_PRIORITY_NICENESS) {
+            eprintln!("Warning: could not raise scheduling priority: {e}");
+        }
+    }
+
+    let conditions = BenchConditions {
+        pinned_cpu,
+        niceness: sys::niceness(),
+        governor: read_governor(pinned_cpu.unwrap_or(0)),
+        load_average: read_load_average(),
+    };
+
+    conditions.warn();
+    conditions
+}
+
+fn read_governor(cpu: usize) -> Option<String> {
+    let path = format!("/sys/devices/system/cpu/cpu{cpu}/cpufreq/scaling_governor");
+    fs::read_to_string(path).ok().map(|s| s.trim().to_string())
+}
+
+fn read_load_average() -> Option<f64> {
+    fs::read_to_string("/proc/loadavg")
+        .ok()?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()
+}
+
+impl BenchConditions {
+    fn warn(&self) {
+        if let Some(governor) = self.governor.as_deref().filter(|g| *g != STABLE_GOVERNOR) {
+            eprintln!(
+                "Warning: CPU frequency governor is \"{governor}\", timings may fluctuate. \
+                Consider switching to \"{STABLE_GOVERNOR}\"."
+            );
+        }
+
+        if let Some(load) = self.load_average.filter(|l| *l > LOAD_WARNING_THRESHOLD) {
+            eprintln!(
+                "Warning: system load average is {load:.2}, concurrent processes may skew timings."
+            );
+        }
+    }
+
+    /// One-line summary of the conditions, printed before benching.
+    pub fn summary(&self) -> String {
+        let cpu = self
+            .pinned_cpu
+            .map_or_else(|| "unpinned".into(), |cpu| format!("cpu {cpu}"));
+        let niceness = self.niceness.map_or_else(|| "-".into(), |n| n.to_string());
+        let governor = self.governor.as_deref().unwrap_or("-");
+        let load = self
+            .load_average
+            .map_or_else(|| "-".into(), |l| format!("{l:.2}"));
+
+        format!(
+            "{ANSI_BOLD}Conditions:{ANSI_RESET} {cpu}, nice {niceness}, governor {governor}, load {load}"
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(target_os = "linux")]
+mod sys {
+    use std::{io, mem};
+
+    pub fn pin_to_cpu(cpu: usize) -> io::Result<()> {
+        if cpu >= libc::CPU_SETSIZE as usize {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "CPU index out of range",
+            ));
+        }
+
+        // SAFETY: `cpu_set_t` is a plain bitmask, the index was bounds-checked above.
+        let result = unsafe {
+            let mut set: libc::cpu_set_t = mem::zeroed();
+            libc::CPU_SET(cpu, &mut set);
+            libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &set)
+        };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    pub fn set_niceness(niceness: i32) -> io::Result<()> {
+        // SAFETY: plain syscall on the current process.
+        let result = unsafe { libc::setpriority(libc::PRIO_PROCESS, 0, niceness) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    pub fn niceness() -> Option<i32> {
+        // NOTE: `getpriority` may legitimately return -1, errno needs to be checked instead.
+        // SAFETY: plain syscalls on the current process.
+        unsafe {
+            *libc::__errno_location() = 0;
+            let niceness = libc::getpriority(libc::PRIO_PROCESS, 0);
+            (*libc::__errno_location() == 0).then_some(niceness)
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod sys {
+    use std::io;
+
+    fn unsupported() -> io::Error {
+        io::Error::new(io::ErrorKind::Unsupported, "only supported on linux")
+    }
+
+    pub fn pin_to_cpu(_cpu: usize) -> io::Result<()> {
+        Err(unsupported())
+    }
+
+    pub fn set_niceness(_niceness: i32) -> io::Result<()> {
+        Err(unsupported())
+    }
+
+    pub fn niceness() -> Option<i32> {
+        None
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchConditions> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &BenchConditions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let number = |x: Option<f64>| x.map_or(JsonValue::Null, JsonValue::Number);
+
+        map.insert(
+            "pinned_cpu".into(),
+            number(value.pinned_cpu.map(|x| x as f64)),
+        );
+        map.insert("niceness".into(), number(value.niceness.map(f64::from)));
+        map.insert(
+            "governor".into(),
+            value
+                .governor
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert("load_average".into(), number(value.load_average));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchConditions {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected conditions to be a JSON object.")?;
+
+        // missing keys are treated like `null`, so that new conditions can be added over time.
+        let number = |key: &str| json.get(key).and_then(|v| v.get::<f64>().copied());
+
+        Ok(BenchConditions {
+            pinned_cpu: number("pinned_cpu").map(|x| x as usize),
+            niceness: number("niceness").map(|x| x as i32),
+            governor: json
+                .get("governor")
+                .and_then(|v| v.get::<String>())
+                .cloned(),
+            load_average: number("load_average"),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::BenchConditions;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn roundtrips_json() {
+        let conditions = BenchConditions {
+            pinned_cpu: Some(3),
+            niceness: Some(-10),
+            governor: Some("performance".into()),
+            load_average: Some(0.25),
+        };
+
+        let json = JsonValue::from(&conditions);
+        assert_eq!(BenchConditions::try_from(&json).unwrap(), conditions);
+    }
+
+    #[test]
+    fn handles_missing_keys() {
+        let json: JsonValue = r#"{ "governor": "powersave" }"#.parse().unwrap();
+        let conditions = BenchConditions::try_from(&json).unwrap();
+        assert_eq!(conditions.governor, Some("powersave".into()));
+        assert_eq!(conditions.pinned_cpu, None);
+        assert_eq!(conditions.load_average, None);
+    }
+
+    #[test]
+    fn summarizes_unknown_conditions() {
+        let summary = BenchConditions::default().summary();
+        assert!(summary.contains("unpinned"));
+        assert!(summary.contains("governor -"));
+    }
+}
@@ -0,0 +1,218 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::template::{
+    all_days, run_multi::child_commands, runner::InputSource, submissions::Submissions, workspace,
+    DataFolder, Day, ANSI_BOLD, ANSI_RESET,
+};
+
+static INPUT_EXTENSION: &str = "txt";
+static ANSWERS_EXTENSION: &str = "answers";
+
+/// An input with the answers it is known to produce.
+/// Shared inputs live in a directory per day, e.g. `data/inputs/05/alice.txt`, with their answers recorded next
+/// to them in `alice.answers`, in the same format the solution prints them.
+struct RecordedInput {
+    name: String,
+    source: InputSource,
+    expected: [Option<String>; 2],
+}
+
+/// Run the solution of a day against all inputs with recorded answers and compare the results.
+/// Without a day, all days with a solution are checked, e.g. to verify answers against committed inputs in CI.
+pub fn handle(day: Option<Day>, is_release: bool) {
+    let days: Vec<Day> = match day {
+        Some(day) if !workspace::has_solution(day) => {
+            eprintln!(
+                "Day {day} has no solution at \"{}\".",
+                workspace::bin_path(day).display()
+            );
+            process::exit(1);
+        }
+        Some(day) => vec![day],
+        None => all_days().filter(|d| workspace::has_solution(*d)).collect(),
+    };
+
+    let mut checked = 0;
+    let mut disagreeing = 0;
+    let submissions = Submissions::read_from_file();
+
+    for day in &days {
+        let inputs = recorded_inputs(*day, &submissions);
+
+        if inputs.is_empty() {
+            if days.len() == 1 {
+                eprintln!(
+                    "No inputs with recorded answers found for day {day}. Add shared inputs to \"{}\" as `<name>.txt`, with their answers in `<name>.{ANSWERS_EXTENSION}`.",
+                    shared_inputs_dir(*day).display()
+                );
+                process::exit(1);
+            }
+            continue;
+        }
+
+        if days.len() > 1 {
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        }
+
+        for input in &inputs {
+            checked += 1;
+            if !check(*day, is_release, input) {
+                disagreeing += 1;
+            }
+        }
+    }
+
+    println!("---");
+    if disagreeing == 0 {
+        println!("🎄 Solution agrees with the recorded answers for all {checked} input(s).");
+    } else {
+        println!(
+            "Solution disagrees with the recorded answers for {disagreeing} of {checked} input(s)."
+        );
+        process::exit(1);
+    }
+}
+
+/// Run the solution against a single input. Returns whether all recorded answers were matched.
+fn check(day: Day, is_release: bool, input: &RecordedInput) -> bool {
+    println!("{ANSI_BOLD}{}{ANSI_RESET}", input.name);
+
+    let output = match child_commands::run_solution_with_input(day, is_release, &input.source) {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run solution: {e}");
+            process::exit(1);
+        }
+    };
+
+    if !output.status.success() {
+        println!("  ✖ Solution failed:");
+        for line in String::from_utf8_lossy(&output.stderr).lines() {
+            println!("    {line}");
+        }
+        return false;
+    }
+
+    let stdout: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(String::from)
+        .collect();
+
+    let answers = child_commands::parse_answers(&stdout);
+    let mut agrees = true;
+
+    for (index, (answer, expected)) in answers.iter().zip(&input.expected).enumerate() {
+        let part = index + 1;
+
+        match (answer, expected) {
+            (Some(answer), Some(expected)) if answer == expected => {
+                println!("  Part {part}: ✔ {answer}");
+            }
+            (Some(answer), Some(expected)) => {
+                println!("  Part {part}: ✖ {answer}, expected {expected}");
+                agrees = false;
+            }
+            (None, Some(expected)) => {
+                println!("  Part {part}: ✖ no answer, expected {expected}");
+                agrees = false;
+            }
+            (Some(answer), None) => {
+                println!("  Part {part}: ? {answer}, no answer recorded");
+            }
+            (None, None) => {}
+        }
+    }
+
+    agrees
+}
+
+/// Your own input, if answers are recorded for it, followed by all shared inputs of a day.
+/// Answers for your own input are read from `data/inputs/<day>.answers`, or else from the answers accepted via `--submit`.
+fn recorded_inputs(day: Day, submissions: &Submissions) -> Vec<RecordedInput> {
+    let mut inputs = vec![];
+
+    let path = DataFolder::Inputs.path_with(day);
+    if workspace::absolute(&path).exists() {
+        let mut expected = read_answers(&path);
+        for (part, answer) in (1..).zip(expected.iter_mut()) {
+            if answer.is_none() {
+                *answer = submissions
+                    .correct_answer(day, part)
+                    .map(|s| s.answer.clone());
+            }
+        }
+
+        if expected.iter().any(Option::is_some) {
+            inputs.push(RecordedInput {
+                name: "your input".into(),
+                source: InputSource::Puzzle,
+                expected,
+            });
+        }
+    }
+
+    inputs.extend(shared_inputs(&shared_inputs_dir(day)).unwrap_or_default());
+    inputs
+}
+
+/// Shared inputs of a day live in a directory named after it, e.g. `data/inputs/05`.
+fn shared_inputs_dir(day: Day) -> PathBuf {
+    DataFolder::Inputs.sub_directory().join(day.to_string())
+}
+
+/// All inputs in a directory with their recorded answers, sorted by name.
+fn shared_inputs(dir: &Path) -> std::io::Result<Vec<RecordedInput>> {
+    let mut inputs: Vec<RecordedInput> = fs::read_dir(workspace::absolute(dir))?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|file_name| {
+            let name = file_name.strip_suffix(&format!(".{INPUT_EXTENSION}"))?;
+            let path = dir.join(&file_name);
+
+            Some(RecordedInput {
+                name: name.to_string(),
+                expected: read_answers(&path),
+                source: InputSource::File(workspace::absolute(path)),
+            })
+        })
+        .collect();
+
+    inputs.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(inputs)
+}
+
+/// The answers recorded next to an input, e.g. `alice.answers` for `alice.txt`.
+fn read_answers(input_path: &Path) -> [Option<String>; 2] {
+    fs::read_to_string(workspace::absolute(
+        input_path.with_extension(ANSWERS_EXTENSION),
+    ))
+    .map(|answers| parse_answers_file(&answers))
+    .unwrap_or_default()
+}
+
+/// Answer files use the format of the solution output, e.g. `Part 1: 142`, so the output of `cargo solve` can be pasted into them.
+fn parse_answers_file(contents: &str) -> [Option<String>; 2] {
+    let lines: Vec<String> = contents.lines().map(String::from).collect();
+    child_commands::parse_answers(&lines)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse_answers_file;
+
+    #[test]
+    fn parses_answers_file() {
+        assert_eq!(
+            parse_answers_file("Part 1: 142\nPart 2: 281\n"),
+            [Some("142".into()), Some("281".into())]
+        );
+        assert_eq!(
+            parse_answers_file("Part 2: 281\n"),
+            [None, Some("281".into())]
+        );
+        assert_eq!(parse_answers_file(""), [None, None]);
+    }
+}
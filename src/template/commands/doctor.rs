@@ -0,0 +1,247 @@
+use std::{env, fs, path::Path, process};
+
+use crate::template::{
+    all_days, aoc_cli, encryption, input_check, readme_benchmarks, readme_stars, timings::Timings,
+    workspace, DataFile, DataFolder, Day, ANSI_BOLD, ANSI_RESET,
+};
+
+/// The first year Advent of Code took place.
+const FIRST_YEAR: u16 = 2015;
+
+/// Collects the problems found by the checks and prints them as they are found.
+#[derive(Default)]
+struct Findings {
+    errors: usize,
+    warnings: usize,
+}
+
+impl Findings {
+    fn error(&mut self, subject: impl AsRef<str>, message: impl AsRef<str>) {
+        self.errors += 1;
+        println!(
+            "  {ANSI_BOLD}Error:{ANSI_RESET} {}: {}",
+            subject.as_ref(),
+            message.as_ref()
+        );
+    }
+
+    fn warning(&mut self, subject: impl AsRef<str>, message: impl AsRef<str>) {
+        self.warnings += 1;
+        println!(
+            "  {ANSI_BOLD}Warning:{ANSI_RESET} {}: {}",
+            subject.as_ref(),
+            message.as_ref()
+        );
+    }
+
+    fn count(&self) -> usize {
+        self.errors + self.warnings
+    }
+
+    /// Print a heading and run the checks of a section below it.
+    fn section(&mut self, title: &str, checks: impl FnOnce(&mut Self)) {
+        println!("{ANSI_BOLD}{title}{ANSI_RESET}");
+        let before = self.count();
+        checks(self);
+        if self.count() == before {
+            println!("  No problems found.");
+        }
+        println!();
+    }
+}
+
+pub fn handle() {
+    let mut findings = Findings::default();
+
+    findings.section("Environment", |findings| {
+        check_aoc_cli(findings);
+        check_year(findings);
+        check_session(findings);
+    });
+
+    findings.section("Project", |findings| {
+        check_readme(findings);
+        check_timings(findings);
+    });
+
+    findings.section("Data files", |findings| {
+        for day in all_days() {
+            if workspace::has_solution(day) {
+                check_input(findings, day);
+                check_examples(findings, day);
+            } else {
+                check_orphaned_data(findings, day);
+            }
+        }
+    });
+
+    println!("---");
+    if findings.count() == 0 {
+        println!("🎄 Everything looks good.");
+    } else {
+        println!(
+            "Found {} error(s) and {} warning(s).",
+            findings.errors, findings.warnings
+        );
+    }
+
+    if findings.errors > 0 {
+        process::exit(1);
+    }
+}
+
+fn check_aoc_cli(findings: &mut Findings) {
+    if aoc_cli::check().is_err() {
+        findings.warning(
+            "aoc-cli",
+            "not installed, it is needed to download inputs and submit answers. Run `cargo install aoc-cli` to install it.",
+        );
+    }
+}
+
+fn check_year(findings: &mut Findings) {
+    match env::var("AOC_YEAR") {
+        Err(_) => findings.warning(
+            "AOC_YEAR",
+            "not set, aoc-cli defaults to the latest event. Set it in the `[env]` section of `.cargo/config.toml`.",
+        ),
+        Ok(value) => {
+            if aoc_cli::get_year().is_none_or(|year| year < FIRST_YEAR) {
+                findings.error(
+                    "AOC_YEAR",
+                    format!("\"{value}\" is not a year of advent of code. Fix it in the `[env]` section of `.cargo/config.toml`."),
+                );
+            }
+        }
+    }
+}
+
+fn check_session(findings: &mut Findings) {
+    match aoc_cli::read_session() {
+        Err(e) => findings.warning("session cookie", e),
+        Ok(session) => {
+            // session cookies are 128 hex characters.
+            if session.len() != 128 || !session.chars().all(|c| c.is_ascii_hexdigit()) {
+                findings.warning(
+                    "session cookie",
+                    "looks malformed. Copy the value of the `session` cookie from adventofcode.com again.",
+                );
+            }
+        }
+    }
+}
+
+fn check_readme(findings: &mut Findings) {
+    let Some(readme) = read(&workspace::readme_path()) else {
+        findings.warning(
+            "README.md",
+            "not found, benchmarks and stars can't be stored.",
+        );
+        return;
+    };
+
+    for (marker, command) in [
+        (readme_benchmarks::MARKER, "cargo time --store"),
+        (readme_stars::MARKER, "cargo stars"),
+    ] {
+        if let Err(e) = readme_benchmarks::check_markers(&readme, marker) {
+            findings.warning(
+                "README.md",
+                format!("{e} `{command}` can't update it. Add a single `{marker}` line where the table should go."),
+            );
+        }
+    }
+}
+
+fn check_timings(findings: &mut Findings) {
+    if let Err(e) = Timings::try_read_from_file() {
+        findings.error(
+            "data/timings.json",
+            format!("malformed, the next `cargo time --store` would replace it: {e} Fix or delete the file."),
+        );
+    }
+}
+
+fn check_input(findings: &mut Findings, day: Day) {
+    let path = DataFolder::Inputs.path_with(day);
+    let subject = path.display().to_string();
+
+    if !workspace::absolute(&path).exists() {
+        findings.error(
+            subject,
+            format!("input is missing. Run `cargo download {day}` to download it."),
+        );
+        return;
+    }
+
+    let Some(input) = read_data(findings, &path) else {
+        return;
+    };
+
+    for issue in input_check::check(&input) {
+        let message = format!("{issue} {}", issue.hint(day));
+        if issue.is_fatal() {
+            findings.error(&subject, message);
+        } else {
+            findings.warning(&subject, message);
+        }
+    }
+}
+
+/// Data files of days without a solution are likely left over, or the solution was not scaffolded.
+fn check_orphaned_data(findings: &mut Findings, day: Day) {
+    let paths = [
+        DataFolder::Inputs.path_with(day),
+        DataFolder::Examples.path_with(DataFile::DayPart(day, 1)),
+        DataFolder::Examples.path_with(DataFile::DayPart(day, 2)),
+    ];
+
+    for path in paths {
+        if workspace::absolute(&path).exists() {
+            findings.warning(
+                path.display().to_string(),
+                format!(
+                    "day {day} has no solution at \"{}\". Run `cargo scaffold {day}` to create it.",
+                    workspace::bin_path(day).display()
+                ),
+            );
+        }
+    }
+}
+
+/// Examples are only used by tests, so issues with them never prevent running a solution.
+fn check_examples(findings: &mut Findings, day: Day) {
+    for part in [1, 2] {
+        let path = DataFolder::Examples.path_with(DataFile::DayPart(day, part));
+
+        // days with a single part or a shared example might not have both files.
+        let Some(example) = read_data(findings, &path) else {
+            continue;
+        };
+
+        for issue in input_check::check(&example) {
+            let message = match issue {
+                input_check::Issue::Empty => {
+                    "example is empty. Paste the example of the puzzle into it.".into()
+                }
+                issue => issue.to_string(),
+            };
+            findings.warning(path.display().to_string(), message);
+        }
+    }
+}
+
+fn read(path: &Path) -> Option<String> {
+    fs::read_to_string(workspace::absolute(path)).ok()
+}
+
+/// Read a data file, decrypting it if needed. Reports files that can't be decrypted.
+fn read_data(findings: &mut Findings, path: &Path) -> Option<String> {
+    match encryption::decrypt_if_encrypted(read(path)?, &workspace::absolute(path)) {
+        Ok(contents) => Some(contents),
+        Err(e) => {
+            findings.error(path.display().to_string(), e.to_string());
+            None
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+};
+
+use crate::template::{encryption, workspace, DataFolder};
+
+pub enum Action {
+    Encrypt,
+    Decrypt,
+}
+
+impl FromStr for Action {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "encrypt" => Ok(Action::Encrypt),
+            "decrypt" => Ok(Action::Decrypt),
+            _ => Err(format!("expected `encrypt` or `decrypt`, got `{s}`.")),
+        }
+    }
+}
+
+/// Encrypt or decrypt all inputs and puzzle descriptions in place.
+/// Files that already are in the requested state, as well as empty files, are skipped.
+pub fn handle(action: &Action) {
+    let key = match encryption::key() {
+        Ok(key) => key,
+        Err(_) => {
+            eprintln!(
+                "`{}` is not set. Set it to a long, random secret and keep it safe, it is needed to decrypt the files again.",
+                encryption::KEY_VARIABLE
+            );
+            process::exit(1);
+        }
+    };
+
+    let mut changed = 0;
+
+    for path in data_files() {
+        let absolute = workspace::absolute(&path);
+
+        let contents = match fs::read_to_string(&absolute) {
+            Ok(contents) => contents,
+            Err(e) => {
+                eprintln!("Failed to read \"{}\": {e}", path.display());
+                process::exit(1);
+            }
+        };
+
+        let is_encrypted = encryption::is_encrypted(&contents);
+
+        let result = match action {
+            Action::Encrypt if !is_encrypted && !contents.is_empty() => {
+                Some(encryption::encrypt(&contents, &key, &absolute))
+            }
+            Action::Decrypt if is_encrypted => {
+                match encryption::decrypt(&contents, &key, &absolute) {
+                    Ok(plaintext) => Some(plaintext),
+                    Err(e) => {
+                        eprintln!("Failed to decrypt \"{}\": {e}", path.display());
+                        process::exit(1);
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(result) = result {
+            if let Err(e) = fs::write(&absolute, result) {
+                eprintln!("Failed to write \"{}\": {e}", path.display());
+                process::exit(1);
+            }
+            println!("{} \"{}\"", past_tense(action), path.display());
+            changed += 1;
+        }
+    }
+
+    println!("---");
+    println!("🎄 {} {changed} file(s).", past_tense(action));
+}
+
+fn past_tense(action: &Action) -> &'static str {
+    match action {
+        Action::Encrypt => "Encrypted",
+        Action::Decrypt => "Decrypted",
+    }
+}
+
+/// All inputs and puzzle descriptions, relative to the workspace root.
+/// Includes shared inputs in the per-day directories, e.g. `data/inputs/05/alice.txt`.
+fn data_files() -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = [DataFolder::Inputs, DataFolder::Puzzles]
+        .into_iter()
+        .flat_map(|folder| {
+            let extension = folder.expected_extension();
+            let dir = folder.sub_directory();
+
+            let mut paths = list_dir(&dir);
+            if matches!(folder, DataFolder::Inputs) {
+                let sub_directories: Vec<PathBuf> = paths
+                    .iter()
+                    .filter(|path| workspace::absolute(path).is_dir())
+                    .cloned()
+                    .collect();
+                paths.extend(sub_directories.iter().flat_map(|dir| list_dir(dir)));
+            }
+
+            paths
+                .into_iter()
+                .filter(move |path| has_extension(path, extension))
+        })
+        .collect();
+
+    files.sort();
+    files
+}
+
+/// Entries of a directory, relative to the workspace root.
+fn list_dir(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(workspace::absolute(dir))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .map(|name| dir.join(name))
+        .collect()
+}
+
+fn has_extension(path: &Path, extension: &str) -> bool {
+    path.extension().is_some_and(|e| e == extension)
+}
@@ -0,0 +1,139 @@
+use std::{path::PathBuf, process};
+
+use crate::template::{
+    all_days, aoc_cli,
+    leaderboard::{format_duration, unlock_timestamp, Leaderboard, Member},
+    Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+pub fn handle(id: Option<u64>, file: Option<PathBuf>) {
+    let leaderboard = match load(id, file) {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("Failed to load leaderboard: {e}");
+            process::exit(1);
+        }
+    };
+
+    print_standings(&leaderboard);
+
+    if let Some(year) = leaderboard.year() {
+        for day in all_days() {
+            print_day(&leaderboard, year, day);
+        }
+    }
+}
+
+fn load(id: Option<u64>, file: Option<PathBuf>) -> Result<Leaderboard, String> {
+    if let Some(file) = file {
+        return Leaderboard::read_from_file(&file);
+    }
+
+    let id = id
+        .or_else(aoc_cli::get_user_id)
+        .ok_or("no leaderboard id specified. Pass it as an argument or set `AOC_USER_ID`.")?;
+
+    let year = aoc_cli::get_year().ok_or("`AOC_YEAR` is not set or not a valid year.")?;
+
+    Leaderboard::fetch(year, id)
+}
+
+fn print_standings(leaderboard: &Leaderboard) {
+    let scores = leaderboard.local_scores();
+    let name_width = name_width(&leaderboard.members);
+
+    let mut members: Vec<&Member> = leaderboard.members.iter().collect();
+    members.sort_by_key(|m| (std::cmp::Reverse(scores[&m.id]), std::cmp::Reverse(m.stars)));
+
+    println!(
+        "{ANSI_BOLD}Leaderboard {}{ANSI_RESET} (owner #{}, {} members)",
+        leaderboard.event,
+        leaderboard.owner_id,
+        leaderboard.members.len()
+    );
+    println!();
+    println!(
+        "{ANSI_BOLD}{:>4}  {:<name_width$}  {:>5}  {:>10}  {:>5}{ANSI_RESET}",
+        "Rank", "Name", "Score", "Recomputed", "Stars"
+    );
+
+    for (rank, member) in members.iter().enumerate() {
+        let recomputed = scores[&member.id];
+        let mismatch = if recomputed == member.local_score {
+            ""
+        } else {
+            " *"
+        };
+
+        println!(
+            "{:>4}  {:<name_width$}  {:>5}  {:>10}  {:>5}{mismatch}",
+            rank + 1,
+            member.display_name(),
+            member.local_score,
+            recomputed,
+            member.stars
+        );
+    }
+
+    if members.iter().any(|m| scores[&m.id] != m.local_score) {
+        println!();
+        println!("{ANSI_ITALIC}* recomputed score differs, e.g. because a day was excluded from scoring.{ANSI_RESET}");
+    }
+}
+
+/// Print the time it took every member to collect the stars of a day, relative to the puzzle unlock.
+fn print_day(leaderboard: &Leaderboard, year: u16, day: Day) {
+    let unlock = unlock_timestamp(year, day);
+    let name_width = name_width(&leaderboard.members);
+
+    let mut finishers: Vec<(&Member, Option<u64>, Option<u64>)> = leaderboard
+        .members
+        .iter()
+        .map(|m| {
+            let part_time = |part| {
+                m.completion(day, part)
+                    .map(|c| c.timestamp.saturating_sub(unlock))
+            };
+            (m, part_time(1), part_time(2))
+        })
+        .filter(|(_, part_1, _)| part_1.is_some())
+        .collect();
+
+    if finishers.is_empty() {
+        return;
+    }
+
+    // members with both stars first, ordered by how fast they got them.
+    finishers.sort_by_key(|(_, part_1, part_2)| (part_2.is_none(), *part_2, *part_1));
+
+    println!();
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!(
+        "{ANSI_BOLD}{:<name_width$}  {:>11}  {:>11}  {:>11}{ANSI_RESET}",
+        "Name", "Part 1", "Part 2", "Delta"
+    );
+
+    for (member, part_1, part_2) in finishers {
+        let format = |secs: Option<u64>| secs.map_or_else(|| "-".into(), format_duration);
+        let delta = part_1
+            .zip(part_2)
+            .map(|(part_1, part_2)| part_2.saturating_sub(part_1));
+
+        println!(
+            "{:<name_width$}  {:>11}  {:>11}  {:>11}",
+            member.display_name(),
+            format(part_1),
+            format(part_2),
+            format(delta)
+        );
+    }
+}
+
+fn name_width(members: &[Member]) -> usize {
+    members
+        .iter()
+        .map(|m| m.display_name().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4)
+}
@@ -1,6 +1,15 @@
 pub mod all;
+pub mod crosscheck;
+pub mod doctor;
 pub mod download;
+pub mod inputs;
+pub mod leaderboard;
+#[cfg(feature = "profile")]
+pub mod profile;
 pub mod read;
+pub mod report;
 pub mod scaffold;
 pub mod solve;
+pub mod stars;
 pub mod time;
+pub mod unscaffold;
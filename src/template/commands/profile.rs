@@ -0,0 +1,125 @@
+use std::{
+    fs::{self, File},
+    io::{BufWriter, Cursor},
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+};
+
+use inferno::{
+    collapse::{perf::Folder, Collapse},
+    flamegraph,
+};
+
+use crate::template::{perf, workspace, DataFile, Day};
+
+/// Cargo profile that is used to build the profiled binary. Shared with `solve --dhat`, as it
+/// is an optimized build with debug symbols.
+static BUILD_PROFILE: &str = "dhat";
+
+pub fn handle(day: Day, part: Option<u8>) {
+    if perf::check().is_err() {
+        eprintln!("command \"perf\" not found or not callable. Install it via your distribution's package manager (e.g. \"linux-tools\" or \"perf\").");
+        process::exit(1);
+    }
+
+    if let Err(e) = fs::create_dir_all(profiles_dir()) {
+        eprintln!("Failed to create profiles directory: {e}");
+        process::exit(1);
+    }
+
+    let file = part.map_or(DataFile::Day(day), |part| DataFile::DayPart(day, part));
+    let data_path = profile_path(file, "perf.data");
+    let folded_path = profile_path(file, "folded");
+    let svg_path = profile_path(file, "svg");
+
+    let binary = build(day);
+
+    let mut args = vec!["--profile".to_string()];
+    if let Some(part) = part {
+        args.push("--part".to_string());
+        args.push(part.to_string());
+    }
+
+    if let Err(e) = perf::record(&data_path, &binary, &args) {
+        eprintln!("failed to call perf: {e}");
+        process::exit(1);
+    }
+
+    let script = match perf::script(&data_path) {
+        Ok(output) => output.stdout,
+        Err(e) => {
+            eprintln!("failed to call perf: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = write_folded(&script, &folded_path) {
+        eprintln!("Failed to write folded stacks: {e}");
+        process::exit(1);
+    }
+
+    if let Err(e) = write_flamegraph(day, part, &folded_path, &svg_path) {
+        eprintln!("Failed to write flamegraph: {e}");
+        process::exit(1);
+    }
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote folded stacks to \"{}\".",
+        folded_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote flamegraph to \"{}\".",
+        svg_path.display()
+    );
+}
+
+fn profiles_dir() -> PathBuf {
+    workspace::absolute(workspace::profiles_dir())
+}
+
+fn profile_path(file: DataFile, extension: &str) -> PathBuf {
+    profiles_dir().join(file.as_path(extension))
+}
+
+/// Build the solution binary with debug symbols and return its path.
+fn build(day: Day) -> PathBuf {
+    let status = Command::new("cargo")
+        .args(["build", "--quiet"])
+        .args(workspace::cargo_bin_args(day))
+        .args(["--profile", BUILD_PROFILE])
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status();
+
+    if !status.is_ok_and(|s| s.success()) {
+        eprintln!("Failed to build solution for day {day}.");
+        process::exit(1);
+    }
+
+    workspace::target_dir()
+        .join(BUILD_PROFILE)
+        .join(day.to_string())
+}
+
+fn write_folded(script: &[u8], path: &Path) -> std::io::Result<()> {
+    let file = BufWriter::new(File::create(path)?);
+    Folder::default().collapse(Cursor::new(script), file)
+}
+
+fn write_flamegraph(
+    day: Day,
+    part: Option<u8>,
+    folded_path: &Path,
+    svg_path: &Path,
+) -> anyhow::Result<()> {
+    let mut options = flamegraph::Options::default();
+    options.title = match part {
+        Some(part) => format!("Day {day} - Part {part}"),
+        None => format!("Day {day}"),
+    };
+
+    let file = BufWriter::new(File::create(svg_path)?);
+    flamegraph::from_files(&mut options, &[folded_path.to_path_buf()], file)?;
+    Ok(())
+}
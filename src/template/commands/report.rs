@@ -0,0 +1,25 @@
+use std::{path::PathBuf, process};
+
+use crate::template::html_report::{self, Report};
+
+pub fn handle(html_path: Option<PathBuf>) {
+    let Some(html_path) = html_path else {
+        eprintln!("No report format specified. Format: cargo report --html <path>");
+        process::exit(1);
+    };
+
+    let report = Report::read();
+
+    match html_report::write(&report, &html_path) {
+        Ok(()) => {
+            println!(
+                "🎄 Successfully wrote report to \"{}\".",
+                html_path.display()
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to write report: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,69 +1,135 @@
 use std::{
     fs::{File, OpenOptions},
-    io::Write,
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
     process,
 };
 
-use crate::template::Day;
+use crate::template::{
+    aoc_cli,
+    module_template::{self, Placeholders, DEFAULT_TEMPLATE},
+    puzzle,
+    scaffold_log::ScaffoldLog,
+    workspace, DataFile, DataFolder, Day,
+};
+
+/// A file created when scaffolding a day, with its initial contents.
+/// `path` is relative to the workspace root.
+pub struct ScaffoldFile {
+    pub path: PathBuf,
+    pub contents: String,
+    pub description: &'static str,
+}
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// The files that scaffolding `day` with `template` creates.
+/// Uses the puzzle description to fill in the module and the examples, if it was downloaded.
+pub fn scaffold_files(
+    day: Day,
+    template: &str,
+) -> Result<Vec<ScaffoldFile>, module_template::Error> {
+    let template = module_template::load(template)?;
+
+    let description = puzzle::read(day);
+    let examples = description
+        .as_deref()
+        .map(puzzle::examples)
+        .unwrap_or_default();
+
+    let placeholders = Placeholders {
+        day,
+        year: aoc_cli::get_year(),
+        title: description.as_deref().and_then(puzzle::title),
+        answers: examples.clone().map(|example| example.answer),
+    };
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+    let mut files = vec![
+        ScaffoldFile {
+            path: workspace::bin_path(day),
+            contents: placeholders.render(&template),
+            description: "module file",
+        },
+        ScaffoldFile {
+            path: DataFolder::Inputs.path_with(day),
+            contents: String::new(),
+            description: "input file",
+        },
+    ];
+
+    // the templates read one example file per part.
+    for (part, example) in (1..).zip(examples) {
+        files.push(ScaffoldFile {
+            path: DataFolder::Examples.path_with(DataFile::DayPart(day, part)),
+            contents: example.input.unwrap_or_default(),
+            description: "example file",
+        });
+    }
+
+    Ok(files)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(workspace::absolute(path))
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("solutions/src/bin/{day}.rs");
+/// Create `file` unless it exists already, in which case it is left untouched.
+/// Returns whether the file was created.
+fn create_missing(file: &ScaffoldFile) -> bool {
+    let ScaffoldFile {
+        path,
+        contents,
+        description,
+    } = file;
+    let path = path.display();
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    let mut handle = match safe_create_file(&file.path) {
+        Ok(handle) => handle,
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+            println!("Skipped existing {description} \"{path}\"");
+            return false;
+        }
         Err(e) => {
-            eprintln!("Failed to create module file: {e}");
+            eprintln!("Failed to create {description}: {e}");
             process::exit(1);
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
+    match handle.write_all(contents.as_bytes()) {
+        Ok(()) if contents.is_empty() => println!("Created empty {description} \"{path}\""),
+        Ok(()) => println!("Created {description} \"{path}\""),
         Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
+            eprintln!("Failed to write {description}: {e}");
             process::exit(1);
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
+    true
+}
+
+/// Scaffold a day. Only missing files are created, so this can also be used to restore files of a scaffolded day.
+pub fn handle(day: Day, template: Option<&str>) {
+    let files = match scaffold_files(day, template.unwrap_or(DEFAULT_TEMPLATE)) {
+        Ok(files) => files,
         Err(e) => {
-            eprintln!("Failed to create input file: {e}");
+            eprintln!("Failed to load template: {e}");
             process::exit(1);
         }
-    }
+    };
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    let mut log = ScaffoldLog::read_from_file();
+
+    for file in &files {
+        if create_missing(file) {
+            log.record(&file.path, &file.contents);
         }
     }
 
+    if let Err(e) = log.store_file() {
+        eprintln!("Failed to record scaffolded files, `cargo unscaffold {day}` will not be able to remove them: {e}");
+    }
+
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
 }
@@ -1,15 +1,16 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{runner::InputSource, workspace, Day};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args: Vec<String> = vec![
-        "run".to_string(),
-        "--bin".to_string(),
-        day.to_string(),
-        "--package".to_string(),
-        "solutions".to_string(),
-    ];
+pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>, input: &InputSource) {
+    // answers can only be submitted for the puzzle input they were computed from.
+    if submit_part.is_some() && *input != InputSource::Puzzle {
+        eprintln!("`--submit` can only be used with your puzzle input, not with the {input}.");
+        process::exit(1);
+    }
+
+    let mut cmd_args: Vec<String> = vec!["run".to_string()];
+    cmd_args.extend(workspace::cargo_bin_args(day));
 
     if dhat {
         cmd_args.extend([
@@ -29,6 +30,8 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    cmd_args.extend(input.to_args());
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -0,0 +1,54 @@
+use std::{path::PathBuf, process};
+
+use crate::template::{
+    aoc_cli,
+    leaderboard::Leaderboard,
+    readme_stars::{self, Stars},
+    submissions::Submissions,
+};
+
+pub fn handle(fetch_leaderboard: bool, leaderboard_path: Option<PathBuf>, user_id: Option<u64>) {
+    let mut stars = Stars::from_submissions(&Submissions::read_from_file());
+
+    if fetch_leaderboard || leaderboard_path.is_some() {
+        let Some(user_id) = user_id.or_else(aoc_cli::get_user_id) else {
+            eprintln!("No user id specified. Pass `--user <id>` or set the `AOC_USER_ID` environment variable.");
+            process::exit(1);
+        };
+
+        let leaderboard = match load(leaderboard_path, user_id) {
+            Ok(leaderboard) => leaderboard,
+            Err(e) => {
+                eprintln!("Failed to load leaderboard: {e}");
+                process::exit(1);
+            }
+        };
+
+        let Some(member) = leaderboard.member(user_id) else {
+            eprintln!("User {user_id} is not a member of the leaderboard.");
+            process::exit(1);
+        };
+
+        stars = stars.merge(&Stars::from_member(member));
+    }
+
+    match readme_stars::update(&stars, aoc_cli::get_year()) {
+        Ok(()) => {
+            println!("Stored progress of {} ⭐.", stars.total());
+        }
+        Err(e) => {
+            eprintln!("Failed to store progress: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Reads the leaderboard from `path` if given, otherwise fetches the private leaderboard owned by `user_id`.
+fn load(path: Option<PathBuf>, user_id: u64) -> Result<Leaderboard, String> {
+    if let Some(path) = path {
+        return Leaderboard::read_from_file(&path);
+    }
+
+    let year = aoc_cli::get_year().ok_or("`AOC_YEAR` is not set or not a valid year.")?;
+    Leaderboard::fetch(year, user_id)
+}
@@ -1,10 +1,11 @@
 use std::collections::HashSet;
 
+use crate::template::bench_env::{self, BenchOptions};
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
+use crate::template::timings::{Timings, TimingsHistory};
 use crate::template::{all_days, readme_benchmarks, Day};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+pub fn handle(day: Option<Day>, run_all: bool, store: bool, chart: bool, options: BenchOptions) {
     let stored_timings = Timings::read_from_file();
 
     let days_to_run = day.map_or_else(
@@ -21,19 +22,27 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let conditions = bench_env::prepare(options);
+    println!("{}", conditions.summary());
+    println!();
+
+    let timings = run_multi(&days_to_run, true, true, Some(&conditions)).unwrap();
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
+        if let Err(e) = TimingsHistory::append(&merged_timings) {
+            eprintln!("Failed to append to the timings history: {e}");
+        }
+
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(merged_timings, chart) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+            Err(e) => {
+                eprintln!("Failed to store updated benchmarks: {e}");
             }
         }
     }
@@ -0,0 +1,123 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    process,
+};
+
+use crate::template::{
+    commands::scaffold::{scaffold_files, ScaffoldFile},
+    module_template,
+    scaffold_log::ScaffoldLog,
+    workspace, Day,
+};
+
+/// Remove a scaffolded day, as long as its module is unchanged since it was scaffolded.
+/// Data files are only removed if they still have their scaffolded contents, e.g. downloaded inputs are kept.
+pub fn handle(day: Day) {
+    let rendered: Vec<_> = module_template::available()
+        .iter()
+        .filter_map(|template| scaffold_files(day, template).ok())
+        .collect();
+
+    let Some(files) = rendered.first() else {
+        eprintln!("Failed to load templates.");
+        process::exit(1);
+    };
+
+    let Some((module, data_files)) = files.split_first() else {
+        return;
+    };
+
+    let mut log = ScaffoldLog::read_from_file();
+
+    let module_contents = match fs::read_to_string(workspace::absolute(&module.path)) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!(
+                "Failed to read module file \"{}\": {e}",
+                module.path.display()
+            );
+            process::exit(1);
+        }
+    };
+
+    if !is_unchanged(&log, &rendered, &module.path, &module_contents) {
+        eprintln!(
+            "Day {day} was modified since it was scaffolded, refusing to remove \"{}\".",
+            module.path.display()
+        );
+        process::exit(1);
+    }
+
+    let mut to_remove = vec![module];
+
+    for file in data_files {
+        match fs::read_to_string(workspace::absolute(&file.path)) {
+            Ok(contents) if is_unchanged(&log, &rendered, &file.path, &contents) => {
+                to_remove.push(file);
+            }
+            Ok(_) => println!(
+                "Keeping modified {} \"{}\"",
+                file.description,
+                file.path.display()
+            ),
+            Err(_) => {}
+        }
+    }
+
+    println!("This removes:");
+    for file in &to_remove {
+        println!("  {}", file.path.display());
+    }
+
+    if !confirm("Continue? [y/N] ") {
+        println!("Aborted.");
+        return;
+    }
+
+    for file in to_remove {
+        match fs::remove_file(workspace::absolute(&file.path)) {
+            Ok(()) => {
+                log.forget(&file.path);
+                println!("Removed {} \"{}\"", file.description, file.path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to remove {}: {e}", file.description);
+                process::exit(1);
+            }
+        }
+    }
+
+    if let Err(e) = log.store_file() {
+        eprintln!("Failed to update the record of scaffolded files: {e}");
+    }
+
+    println!("---");
+    println!("🎄 Removed day {day}.");
+}
+
+/// Whether `contents` of `path` are what `cargo scaffold` wrote, according to the scaffold log.
+/// Files that were scaffolded before the log existed are compared against the current templates instead.
+fn is_unchanged(
+    log: &ScaffoldLog,
+    rendered: &[Vec<ScaffoldFile>],
+    path: &Path,
+    contents: &str,
+) -> bool {
+    log.matches(path, contents).unwrap_or_else(|| {
+        rendered
+            .iter()
+            .flatten()
+            .any(|file| file.path == path && file.contents == contents)
+    })
+}
+
+fn confirm(prompt: &str) -> bool {
+    print!("{prompt}");
+    let _ = io::stdout().flush();
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer).is_ok()
+        && matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
@@ -0,0 +1,216 @@
+/// Module that encrypts data files at rest, so that inputs and puzzles can be committed without publishing them.
+/// Files are encrypted with XChaCha20-Poly1305, using a key derived from the `AOC_INPUT_KEY` environment variable.
+/// Each ciphertext is bound to the path of its file, so that encrypted files can't be swapped unnoticed.
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+    Key, XChaCha20Poly1305, XNonce,
+};
+use sha2::{Digest, Sha256};
+
+use crate::template::workspace;
+
+pub static KEY_VARIABLE: &str = "AOC_INPUT_KEY";
+
+/// First line of every encrypted file.
+static HEADER: &str = "aoc-encrypted v2";
+/// Hex-encoded ciphertext is wrapped to keep lines of encrypted files short.
+const LINE_WIDTH: usize = 64;
+
+#[derive(Debug)]
+pub enum Error {
+    MissingKey,
+    Malformed,
+    Decryption,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingKey => write!(f, "file is encrypted, but `{KEY_VARIABLE}` is not set."),
+            Error::Malformed => write!(f, "encrypted file is malformed."),
+            Error::Decryption => {
+                write!(f, "could not decrypt file, is `{KEY_VARIABLE}` correct?")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Read the key from the environment. Any secret works, a long random string is recommended.
+pub fn key() -> Result<Key, Error> {
+    env::var(KEY_VARIABLE)
+        .ok()
+        .filter(|secret| !secret.is_empty())
+        .map(|secret| derive_key(&secret))
+        .ok_or(Error::MissingKey)
+}
+
+fn derive_key(secret: &str) -> Key {
+    Sha256::digest(secret.as_bytes())
+}
+
+pub fn is_encrypted(contents: &str) -> bool {
+    contents.starts_with(HEADER)
+}
+
+/// The associated data of a file: its path relative to the workspace root, with `/` as separator.
+/// `path` is the path the file is read from or written to.
+fn associated_data(path: &Path) -> Vec<u8> {
+    let canonical = |path: &Path| fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let path = canonical(path);
+    let root: PathBuf = canonical(workspace::root());
+
+    path.strip_prefix(&root)
+        .unwrap_or(&path)
+        .to_string_lossy()
+        .replace('\\', "/")
+        .into_bytes()
+}
+
+/// Encrypt the contents of the file at `path`.
+pub fn encrypt(plaintext: &str, key: &Key, path: &Path) -> String {
+    let cipher = XChaCha20Poly1305::new(key);
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let payload = Payload {
+        msg: plaintext.as_bytes(),
+        aad: &associated_data(path),
+    };
+
+    // encryption only fails for plaintexts of several gigabytes.
+    let ciphertext = cipher
+        .encrypt(&nonce, payload)
+        .expect("plaintext is too large to encrypt.");
+
+    let mut lines = vec![HEADER.to_string(), to_hex(&nonce)];
+    lines.extend(
+        to_hex(&ciphertext)
+            .as_bytes()
+            .chunks(LINE_WIDTH)
+            .map(|chunk| String::from_utf8_lossy(chunk).to_string()),
+    );
+
+    let mut encrypted = lines.join("\n");
+    encrypted.push('\n');
+    encrypted
+}
+
+/// Decrypt the contents of the file at `path`. Fails if the file was encrypted for a different path.
+pub fn decrypt(contents: &str, key: &Key, path: &Path) -> Result<String, Error> {
+    let mut lines = contents.lines();
+
+    if lines.next() != Some(HEADER) {
+        return Err(Error::Malformed);
+    }
+
+    let nonce = lines.next().and_then(from_hex).ok_or(Error::Malformed)?;
+    if nonce.len() != 24 {
+        return Err(Error::Malformed);
+    }
+
+    let ciphertext = from_hex(&lines.collect::<String>()).ok_or(Error::Malformed)?;
+
+    let payload = Payload {
+        msg: ciphertext.as_slice(),
+        aad: &associated_data(path),
+    };
+
+    let plaintext = XChaCha20Poly1305::new(key)
+        .decrypt(XNonce::from_slice(&nonce), payload)
+        .map_err(|_| Error::Decryption)?;
+
+    String::from_utf8(plaintext).map_err(|_| Error::Malformed)
+}
+
+/// Decrypt the contents of the file at `path` if they are encrypted, otherwise return them unchanged.
+pub fn decrypt_if_encrypted(contents: String, path: &Path) -> Result<String, Error> {
+    if is_encrypted(&contents) {
+        decrypt(&contents, &key()?, path)
+    } else {
+        Ok(contents)
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn from_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::Path;
+
+    use super::{decrypt, derive_key, encrypt, from_hex, is_encrypted, to_hex, Error};
+
+    #[test]
+    fn roundtrips() {
+        let key = derive_key("hunter2");
+        let path = Path::new("data/inputs/01.txt");
+        let input = "1abc2\npqr3stu8vwx\n".repeat(10);
+
+        let encrypted = encrypt(&input, &key, path);
+        assert!(is_encrypted(&encrypted));
+        assert!(!encrypted.contains("pqr3stu8vwx"));
+        assert!(encrypted.lines().all(|line| line.len() <= 64));
+        assert_eq!(decrypt(&encrypted, &key, path).unwrap(), input);
+    }
+
+    #[test]
+    fn fails_with_wrong_key() {
+        let path = Path::new("data/inputs/01.txt");
+        let encrypted = encrypt("1abc2\n", &derive_key("hunter2"), path);
+        assert!(matches!(
+            decrypt(&encrypted, &derive_key("hunter3"), path),
+            Err(Error::Decryption)
+        ));
+    }
+
+    #[test]
+    fn fails_for_swapped_files() {
+        let key = derive_key("hunter2");
+        let encrypted = encrypt("1abc2\n", &key, Path::new("data/inputs/01.txt"));
+        assert!(matches!(
+            decrypt(&encrypted, &key, Path::new("data/inputs/02.txt")),
+            Err(Error::Decryption)
+        ));
+    }
+
+    #[test]
+    fn fails_for_malformed_file() {
+        let key = derive_key("hunter2");
+        let path = Path::new("data/inputs/01.txt");
+        assert!(matches!(
+            decrypt("aoc-encrypted v2\nxyz\n", &key, path),
+            Err(Error::Malformed)
+        ));
+        assert!(matches!(
+            decrypt("1abc2\n", &key, path),
+            Err(Error::Malformed)
+        ));
+    }
+
+    #[test]
+    fn roundtrips_hex() {
+        assert_eq!(to_hex(&[0, 15, 255]), "000fff");
+        assert_eq!(from_hex("000fff"), Some(vec![0, 15, 255]));
+        assert_eq!(from_hex("0"), None);
+        assert_eq!(from_hex("zz"), None);
+    }
+}
@@ -0,0 +1,540 @@
+/// Module that renders timings, heap allocations and the timings history into a self-contained HTML page.
+/// All styles, scripts and charts are inlined, so the page can be published as a single file.
+use std::{fmt::Write, fs, io, path::Path};
+
+use crate::template::{
+    all_days,
+    allocations::Allocations,
+    svg_chart::{format_nanos, LogScale},
+    timings::{Timing, Timings, TimingsHistory},
+    Day,
+};
+
+const CHART_WIDTH: f64 = 640.0;
+const BAR_HEIGHT: f64 = 18.0;
+const LABEL_WIDTH: f64 = 64.0;
+
+static STYLE: &str = r#"
+body { font-family: system-ui, sans-serif; margin: 2rem auto; max-width: 60rem; color: #1f2328; }
+h1, h2 { font-weight: 600; }
+table { border-collapse: collapse; width: 100%; margin-bottom: 2rem; }
+th, td { padding: .35rem .75rem; border-bottom: 1px solid #d0d7de; text-align: right; }
+th:first-child, td:first-child { text-align: left; }
+th[data-sortable] { cursor: pointer; user-select: none; }
+th[data-sortable]::after { content: " ⇅"; color: #8c959f; }
+code { font-size: .9em; }
+svg text { font-size: 12px; fill: #1f2328; }
+.part-1 { fill: #2f81f7; }
+.part-2 { fill: #f0883e; }
+.total { fill: #3fb950; }
+.history { fill: none; stroke: #3fb950; stroke-width: 2; }
+.days { display: grid; grid-template-columns: repeat(auto-fill, minmax(14rem, 1fr)); gap: 1rem; }
+.day h3 { margin: 0 0 .25rem; font-size: 1rem; }
+"#;
+
+static SCRIPT: &str = r#"
+document.querySelectorAll("table[data-sortable]").forEach((table) => {
+  table.querySelectorAll("th[data-sortable]").forEach((th, column) => {
+    let ascending = true;
+    th.addEventListener("click", () => {
+      const body = table.tBodies[0];
+      const rows = Array.from(body.rows);
+      const value = (row) => parseFloat(row.cells[column].dataset.sort);
+      rows.sort((a, b) => {
+        const [x, y] = [value(a), value(b)];
+        if (isNaN(x)) return 1;
+        if (isNaN(y)) return -1;
+        return ascending ? x - y : y - x;
+      });
+      ascending = !ascending;
+      rows.forEach((row) => body.appendChild(row));
+    });
+  });
+});
+"#;
+
+/// Everything shown in the report.
+#[derive(Default)]
+pub struct Report {
+    pub timings: Timings,
+    /// Heap profile of every part that was run with `--dhat`.
+    pub allocations: Vec<(Day, u8, Allocations)>,
+    pub history: TimingsHistory,
+}
+
+impl Report {
+    /// Collect the stored timings, heap profiles and timings history.
+    pub fn read() -> Self {
+        let allocations = all_days()
+            .flat_map(|day| [(day, 1), (day, 2)])
+            .filter_map(|(day, part)| Some((day, part, Allocations::read(day, part)?)))
+            .collect();
+
+        Self {
+            timings: Timings::read_from_file(),
+            allocations,
+            history: TimingsHistory::read_from_file(),
+        }
+    }
+}
+
+pub fn write(report: &Report, path: &Path) -> Result<(), io::Error> {
+    fs::write(path, render(report))
+}
+
+fn render(report: &Report) -> String {
+    let timings = &report.timings;
+    let mut html = String::new();
+
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+    html.push_str("<meta charset=\"utf-8\">\n");
+    html.push_str("<title>Advent of Code Benchmarks</title>\n");
+    let _ = writeln!(html, "<style>{STYLE}</style>");
+    html.push_str("</head>\n<body>\n");
+    html.push_str("<h1>Advent of Code Benchmarks</h1>\n");
+
+    if timings.data.is_empty() {
+        html.push_str("<p>No timings stored yet. Run <code>cargo time --store</code> first.</p>\n");
+    } else {
+        let _ = writeln!(
+            html,
+            "<p><strong>Total: {:.2}ms</strong></p>",
+            timings.total_millis()
+        );
+        html.push_str("<h2>Timings</h2>\n");
+        html.push_str(&render_table(timings));
+        html.push_str("<h2>Total per day</h2>\n");
+        html.push_str("<p>Logarithmic scale.</p>\n");
+        html.push_str(&render_total_chart(timings));
+        html.push_str("<h2>Parts per day</h2>\n");
+        html.push_str(&render_day_charts(timings));
+    }
+
+    html.push_str("<h2>Allocations</h2>\n");
+    if report.allocations.is_empty() {
+        html.push_str(
+            "<p>No heap profiles yet. Run <code>cargo solve &lt;day&gt; --dhat</code> first.</p>\n",
+        );
+    } else {
+        html.push_str(&render_allocations(&report.allocations));
+    }
+
+    html.push_str("<h2>History</h2>\n");
+    if report.history.entries.is_empty() {
+        html.push_str(
+            "<p>No history yet. Every <code>cargo time --store</code> adds an entry.</p>\n",
+        );
+    } else {
+        html.push_str(&render_history_chart(&report.history));
+        html.push_str(&render_history_table(&report.history));
+    }
+
+    let _ = writeln!(html, "<script>{SCRIPT}</script>");
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn render_table(timings: &Timings) -> String {
+    let mut html = String::new();
+
+    html.push_str("<table data-sortable>\n<thead>\n<tr>");
+    for header in [
+        "Day", "Part 1", "Part 2", "Total", "CPU", "Governor", "Load",
+    ] {
+        let _ = write!(html, "<th data-sortable>{header}</th>");
+    }
+    html.push_str("</tr>\n</thead>\n<tbody>\n");
+
+    for timing in &timings.data {
+        let conditions = timing.conditions.clone().unwrap_or_default();
+
+        html.push_str("<tr>");
+        push_cell(
+            &mut html,
+            Some(f64::from(timing.day.into_inner())),
+            &format!("Day {}", timing.day),
+        );
+        push_duration_cell(&mut html, timing, 1);
+        push_duration_cell(&mut html, timing, 2);
+        push_cell(
+            &mut html,
+            Some(timing.total_nanos),
+            &format_nanos(timing.total_nanos),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        push_cell(
+            &mut html,
+            conditions.pinned_cpu.map(|cpu| cpu as f64),
+            &conditions
+                .pinned_cpu
+                .map_or_else(|| "-".into(), |cpu| cpu.to_string()),
+        );
+        push_cell(
+            &mut html,
+            None,
+            &escape(conditions.governor.as_deref().unwrap_or("-")),
+        );
+        push_cell(
+            &mut html,
+            conditions.load_average,
+            &conditions
+                .load_average
+                .map_or_else(|| "-".into(), |load| format!("{load:.2}")),
+        );
+        html.push_str("</tr>\n");
+    }
+
+    html.push_str("</tbody>\n</table>\n");
+    html
+}
+
+fn push_cell(html: &mut String, sort: Option<f64>, content: &str) {
+    let sort = sort.map_or_else(|| "NaN".into(), |x| x.to_string());
+    let _ = write!(html, "<td data-sort=\"{sort}\">{content}</td>");
+}
+
+fn push_duration_cell(html: &mut String, timing: &Timing, part: u8) {
+    let label = match part {
+        1 => timing.part_1.as_deref(),
+        _ => timing.part_2.as_deref(),
+    };
+
+    push_cell(
+        html,
+        timing.part_nanos(part),
+        &label.map_or_else(|| "-".into(), |l| format!("<code>{}</code>", escape(l))),
+    );
+}
+
+/// Horizontal bar chart of the total time per day, on a logarithmic scale.
+fn render_total_chart(timings: &Timings) -> String {
+    let values: Vec<f64> = timings.data.iter().map(|t| t.total_nanos).collect();
+    let scale = LogScale::new(&values);
+
+    #[allow(clippy::cast_precision_loss)]
+    let height = BAR_HEIGHT * 1.5 * timings.data.len() as f64;
+    let bar_width = CHART_WIDTH - LABEL_WIDTH * 2.0;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{CHART_WIDTH}\" height=\"{height}\" role=\"img\">\n"
+    );
+
+    for (i, timing) in timings.data.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let y = BAR_HEIGHT * 1.5 * i as f64;
+        let width = scale.fraction(timing.total_nanos) * bar_width;
+
+        let _ = writeln!(
+            svg,
+            "<text x=\"0\" y=\"{}\">Day {}</text>\
+            <rect class=\"total\" x=\"{LABEL_WIDTH}\" y=\"{y}\" width=\"{width:.1}\" height=\"{BAR_HEIGHT}\"></rect>\
+            <text x=\"{:.1}\" y=\"{}\">{}</text>",
+            y + BAR_HEIGHT * 0.75,
+            timing.day,
+            LABEL_WIDTH + width + 4.0,
+            y + BAR_HEIGHT * 0.75,
+            format_nanos(timing.total_nanos),
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// One small linear-scale chart per day, comparing both parts.
+fn render_day_charts(timings: &Timings) -> String {
+    let mut html = String::from("<div class=\"days\">\n");
+    let width = 200.0;
+
+    for timing in &timings.data {
+        let parts = [timing.part_nanos(1), timing.part_nanos(2)];
+        let max = parts.iter().flatten().copied().fold(0_f64, f64::max);
+
+        let _ = writeln!(
+            html,
+            "<div class=\"day\"><h3>Day {}</h3>\n<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{}\" role=\"img\">",
+            timing.day,
+            BAR_HEIGHT * 3.0,
+        );
+
+        for (i, nanos) in parts.iter().enumerate() {
+            #[allow(clippy::cast_precision_loss)]
+            let y = BAR_HEIGHT * 1.5 * i as f64;
+            let bar = match nanos {
+                Some(nanos) if max > 0.0 => nanos / max * (width - 40.0),
+                _ => 0.0,
+            };
+
+            let _ = writeln!(
+                html,
+                "<text x=\"0\" y=\"{}\">P{}</text><rect class=\"part-{}\" x=\"24\" y=\"{y}\" width=\"{bar:.1}\" height=\"{BAR_HEIGHT}\"><title>{}</title></rect>",
+                y + BAR_HEIGHT * 0.75,
+                i + 1,
+                i + 1,
+                nanos.map_or_else(|| "-".into(), format_nanos),
+            );
+        }
+
+        html.push_str("</svg></div>\n");
+    }
+
+    html.push_str("</div>\n");
+    html
+}
+
+fn render_allocations(allocations: &[(Day, u8, Allocations)]) -> String {
+    let mut html = String::new();
+
+    html.push_str("<table data-sortable>\n<thead>\n<tr>");
+    for header in ["Day", "Part", "Allocated", "Blocks", "Peak"] {
+        let _ = write!(html, "<th data-sortable>{header}</th>");
+    }
+    html.push_str("</tr>\n</thead>\n<tbody>\n");
+
+    #[allow(clippy::cast_precision_loss)]
+    for (day, part, allocations) in allocations {
+        html.push_str("<tr>");
+        push_cell(
+            &mut html,
+            Some(f64::from(day.into_inner())),
+            &format!("Day {day}"),
+        );
+        push_cell(&mut html, Some(f64::from(*part)), &part.to_string());
+        push_cell(
+            &mut html,
+            Some(allocations.total_bytes as f64),
+            &format_bytes(allocations.total_bytes),
+        );
+        push_cell(
+            &mut html,
+            Some(allocations.total_blocks as f64),
+            &allocations.total_blocks.to_string(),
+        );
+        push_cell(
+            &mut html,
+            Some(allocations.peak_bytes as f64),
+            &format_bytes(allocations.peak_bytes),
+        );
+        html.push_str("</tr>\n");
+    }
+
+    html.push_str("</tbody>\n</table>\n");
+    html
+}
+
+/// Line chart of the total time of every history entry, on a linear scale starting at zero.
+fn render_history_chart(history: &TimingsHistory) -> String {
+    let totals: Vec<f64> = history
+        .entries
+        .iter()
+        .map(|entry| entry.timings.total_millis())
+        .collect();
+    let max = totals.iter().copied().fold(0_f64, f64::max);
+    let height = BAR_HEIGHT * 8.0;
+    let plot_width = CHART_WIDTH - LABEL_WIDTH * 2.0;
+
+    #[allow(clippy::cast_precision_loss)]
+    let points: Vec<String> = totals
+        .iter()
+        .enumerate()
+        .map(|(i, total)| {
+            let x = match totals.len() {
+                1 => plot_width / 2.0,
+                n => plot_width * i as f64 / (n - 1) as f64,
+            };
+            let y = if max > 0.0 {
+                height - total / max * height
+            } else {
+                height
+            };
+            format!("{:.1},{y:.1}", LABEL_WIDTH + x)
+        })
+        .collect();
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{CHART_WIDTH}\" height=\"{}\" role=\"img\">\n\
+        <text x=\"0\" y=\"12\">{max:.2}ms</text><text x=\"0\" y=\"{height}\">0ms</text>\n\
+        <polyline class=\"history\" points=\"{}\"></polyline>\n</svg>\n",
+        height + 4.0,
+        points.join(" "),
+    )
+}
+
+fn render_history_table(history: &TimingsHistory) -> String {
+    let mut html = String::new();
+
+    html.push_str("<table data-sortable>\n<thead>\n<tr>");
+    for header in ["Recorded (UTC)", "Days", "Total"] {
+        let _ = write!(html, "<th data-sortable>{header}</th>");
+    }
+    html.push_str("</tr>\n</thead>\n<tbody>\n");
+
+    // newest entries first.
+    #[allow(clippy::cast_precision_loss)]
+    for entry in history.entries.iter().rev() {
+        html.push_str("<tr>");
+        push_cell(
+            &mut html,
+            Some(entry.recorded_at as f64),
+            &format_timestamp(entry.recorded_at),
+        );
+        push_cell(
+            &mut html,
+            Some(entry.timings.data.len() as f64),
+            &entry.timings.data.len().to_string(),
+        );
+        push_cell(
+            &mut html,
+            Some(entry.timings.total_millis()),
+            &format!("{:.2}ms", entry.timings.total_millis()),
+        );
+        html.push_str("</tr>\n");
+    }
+
+    html.push_str("</tbody>\n</table>\n");
+    html
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        b if b < 1024 => format!("{b} B"),
+        b if b < 1024 * 1024 => format!("{:.1} KiB", b as f64 / 1024.0),
+        b => format!("{:.1} MiB", b as f64 / (1024.0 * 1024.0)),
+    }
+}
+
+/// Formats seconds since the unix epoch as `YYYY-MM-DD HH:MM` in UTC.
+fn format_timestamp(secs: u64) -> String {
+    let days = secs / 86_400;
+    let (hours, minutes) = (secs % 86_400 / 3600, secs % 3600 / 60);
+
+    // civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02} {hours:02}:{minutes:02}")
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{escape, format_bytes, format_timestamp, render, Report};
+    use crate::{
+        day,
+        template::{
+            allocations::Allocations,
+            timings::{HistoryEntry, Timing, Timings, TimingsHistory},
+        },
+    };
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    part_1: Some("10ms".into()),
+                    part_2: Some("20ms".into()),
+                    total_nanos: 3e+7,
+                    conditions: None,
+                },
+                Timing {
+                    day: day!(2),
+                    part_1: Some("30µs".into()),
+                    part_2: None,
+                    total_nanos: 3e+4,
+                    conditions: None,
+                },
+            ],
+        }
+    }
+
+    fn get_mock_report() -> Report {
+        Report {
+            timings: get_mock_timings(),
+            allocations: vec![(
+                day!(1),
+                1,
+                Allocations {
+                    total_bytes: 2048,
+                    total_blocks: 3,
+                    peak_bytes: 1024,
+                },
+            )],
+            history: TimingsHistory {
+                entries: vec![
+                    HistoryEntry {
+                        recorded_at: 1_733_011_200,
+                        timings: get_mock_timings(),
+                    },
+                    HistoryEntry {
+                        recorded_at: 1_733_097_600,
+                        timings: get_mock_timings(),
+                    },
+                ],
+            },
+        }
+    }
+
+    #[test]
+    fn renders_self_contained_page() {
+        let html = render(&get_mock_report());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<style>"));
+        assert!(html.contains("<script>"));
+        assert!(!html.contains("src=\"http"));
+        assert!(!html.contains("href=\"http"));
+        // timings: header + 2 days, allocations: header + 1 part, history: header + 2 entries.
+        assert_eq!(html.matches("<tr>").count(), 8);
+    }
+
+    #[test]
+    fn renders_allocations_and_history() {
+        let html = render(&get_mock_report());
+        assert!(html.contains("2.0 KiB"));
+        assert!(html.contains("1.0 KiB"));
+        assert!(html.contains("<polyline"));
+        assert!(html.contains("2024-12-02 00:00"));
+    }
+
+    #[test]
+    fn renders_empty_timings() {
+        let html = render(&Report::default());
+        assert!(html.contains("No timings stored yet."));
+        assert!(html.contains("No heap profiles yet."));
+        assert!(html.contains("No history yet."));
+    }
+
+    #[test]
+    fn escapes_html() {
+        assert_eq!(escape("<a & \"b\">"), "&lt;a &amp; &quot;b&quot;&gt;");
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(951_827_696), "2000-02-29 12:34");
+        assert_eq!(format_timestamp(1_733_011_200), "2024-12-01 00:00");
+    }
+}
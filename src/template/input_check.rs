@@ -0,0 +1,169 @@
+/// Module that sanity-checks puzzle inputs before they are passed to a solution.
+/// Catches the usual setup mistakes, which otherwise surface as confusing parse errors.
+use std::fmt::Display;
+
+use anyhow::bail;
+
+use crate::template::{runner::InputSource, DataFolder, Day, ANSI_BOLD, ANSI_RESET};
+
+/// A problem found in an input.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Issue {
+    Empty,
+    HtmlPage,
+    CarriageReturns,
+    NonAscii { line: usize, column: usize, c: char },
+}
+
+impl Issue {
+    /// Fatal issues make running a solution pointless. Other issues are reported, but the solution still runs.
+    pub fn is_fatal(&self) -> bool {
+        matches!(self, Issue::Empty | Issue::HtmlPage)
+    }
+
+    /// A suggestion how to fix the issue.
+    pub fn hint(&self, day: Day) -> String {
+        match self {
+            Issue::Empty => format!(
+                "Run `cargo download {day}` or paste your input into \"{}\".",
+                DataFolder::Inputs.path_with(day).display()
+            ),
+            Issue::HtmlPage => format!(
+                "Your session cookie is probably invalid or expired. Update it and run `cargo download {day}` again."
+            ),
+            Issue::CarriageReturns => {
+                "Line endings are converted to LF before running the solution.".into()
+            }
+            Issue::NonAscii { .. } => {
+                "Puzzle inputs are plain ASCII, this is likely a copy-paste artifact.".into()
+            }
+        }
+    }
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Issue::Empty => write!(f, "input is empty."),
+            Issue::HtmlPage => write!(f, "input is an HTML page, not a puzzle input."),
+            Issue::CarriageReturns => write!(f, "input has Windows line endings (CRLF)."),
+            Issue::NonAscii { line, column, c } => write!(
+                f,
+                "input contains non-ASCII character {c:?} at line {line}, column {column}."
+            ),
+        }
+    }
+}
+
+/// Find all issues of an input.
+pub fn check(input: &str) -> Vec<Issue> {
+    if input.trim().is_empty() {
+        return vec![Issue::Empty];
+    }
+
+    let mut issues = vec![];
+
+    let start = input.trim_start().to_lowercase();
+    if start.starts_with("<!doctype html") || start.starts_with("<html") {
+        issues.push(Issue::HtmlPage);
+    }
+
+    if input.contains("\r\n") {
+        issues.push(Issue::CarriageReturns);
+    }
+
+    let non_ascii = input.lines().enumerate().find_map(|(i, line)| {
+        line.chars()
+            .enumerate()
+            .find(|(_, c)| !c.is_ascii())
+            .map(|(pos, c)| Issue::NonAscii {
+                line: i + 1,
+                column: pos + 1,
+                c,
+            })
+    });
+    issues.extend(non_ascii);
+
+    issues
+}
+
+/// Normalize line endings to LF, and make sure the input ends with exactly one newline.
+pub fn normalize(input: &str) -> String {
+    let mut input = input
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string();
+    input.push('\n');
+    input
+}
+
+/// Check an input before it is passed to a solution.
+/// Issues are printed to stderr, fatal ones abort with an error. Returns the normalized input.
+/// Hints refer to `data/inputs`, so they are only shown for the puzzle input.
+pub fn prepare(input: &str, source: &InputSource, day: Day) -> anyhow::Result<String> {
+    let issues = check(input);
+
+    for issue in &issues {
+        let label = if issue.is_fatal() { "Error" } else { "Warning" };
+        match source {
+            InputSource::Puzzle => eprintln!(
+                "{ANSI_BOLD}{label}:{ANSI_RESET} {issue} {}",
+                issue.hint(day)
+            ),
+            _ => eprintln!("{ANSI_BOLD}{label}:{ANSI_RESET} {issue}"),
+        }
+    }
+
+    if issues.iter().any(Issue::is_fatal) {
+        bail!("the {source} for day {day} is not usable.");
+    }
+
+    Ok(normalize(input))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, normalize, Issue};
+
+    #[test]
+    fn accepts_valid_input() {
+        assert!(check("1abc2\npqr3stu8vwx\n").is_empty());
+    }
+
+    #[test]
+    fn detects_empty_input() {
+        assert_eq!(check(""), vec![Issue::Empty]);
+        assert_eq!(check("\n \n"), vec![Issue::Empty]);
+    }
+
+    #[test]
+    fn detects_html_page() {
+        let issues = check("<!DOCTYPE html>\n<html lang=\"en-us\">\n<head>\n");
+        assert!(issues.contains(&Issue::HtmlPage));
+        assert!(issues.iter().any(Issue::is_fatal));
+    }
+
+    #[test]
+    fn detects_carriage_returns() {
+        assert_eq!(check("1\r\n2\r\n"), vec![Issue::CarriageReturns]);
+    }
+
+    #[test]
+    fn detects_non_ascii() {
+        assert_eq!(
+            check("abc\nd\u{a0}e\n"),
+            vec![Issue::NonAscii {
+                line: 2,
+                column: 2,
+                c: '\u{a0}'
+            }]
+        );
+    }
+
+    #[test]
+    fn normalizes_input() {
+        assert_eq!(normalize("1\r\n2"), "1\n2\n");
+        assert_eq!(normalize("1\n2\n\n\n"), "1\n2\n");
+        assert_eq!(normalize("1\n2\n"), "1\n2\n");
+    }
+}
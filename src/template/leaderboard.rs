@@ -0,0 +1,376 @@
+/// Module that parses private leaderboards.
+/// The expected format is the JSON served at `https://adventofcode.com/{year}/leaderboard/private/view/{id}.json`.
+use std::{
+    collections::HashMap,
+    fs,
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+    str::FromStr,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{all_days, aoc_cli, Day};
+
+/// Puzzles unlock at midnight EST, which is UTC-5.
+const UNLOCK_UTC_OFFSET_SECS: u64 = 5 * 3600;
+
+/// A private leaderboard for a single event.
+#[derive(Clone, Debug)]
+pub struct Leaderboard {
+    pub owner_id: u64,
+    pub event: String,
+    pub members: Vec<Member>,
+}
+
+/// A member of a private leaderboard, including every star they collected.
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub id: u64,
+    /// Anonymous users do not have a name.
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub stars: u64,
+    pub completions: Vec<Completion>,
+}
+
+/// A single collected star.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Completion {
+    pub day: Day,
+    pub part: u8,
+    /// Unix timestamp (in seconds) of when the star was collected.
+    pub timestamp: u64,
+}
+
+impl Leaderboard {
+    /// Read a leaderboard from a JSON file.
+    pub fn read_from_file(path: &Path) -> Result<Self, String> {
+        fs::read_to_string(path)
+            .map_err(|e| format!("could not read leaderboard '{}': {e}", path.display()))
+            .and_then(Leaderboard::try_from)
+    }
+
+    /// Download a leaderboard via `curl`, authenticating with the session cookie used by aoc-cli.
+    /// The cookie is passed on stdin, so that it does not show up in the process list.
+    pub fn fetch(year: u16, id: u64) -> Result<Self, String> {
+        let session = aoc_cli::read_session()?;
+        let url = format!("https://adventofcode.com/{year}/leaderboard/private/view/{id}.json");
+
+        let mut child = Command::new("curl")
+            .args(["--fail", "--silent", "--show-error", "--header", "@-"])
+            .arg(url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|_| "command \"curl\" not found or not callable.".to_string())?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            writeln!(stdin, "Cookie: session={session}")
+                .map_err(|e| format!("could not pass session cookie to curl: {e}"))?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .map_err(|_| "command \"curl\" not found or not callable.".to_string())?;
+
+        if !output.status.success() {
+            return Err("could not download leaderboard, is the session cookie valid?".into());
+        }
+
+        Leaderboard::try_from(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    pub fn member(&self, id: u64) -> Option<&Member> {
+        self.members.iter().find(|m| m.id == id)
+    }
+
+    pub fn year(&self) -> Option<u16> {
+        self.event.parse().ok()
+    }
+
+    /// Recompute local scores from star timestamps: for every star, the first member to collect it
+    /// gets one point per member, the second one point less, and so on.
+    pub fn local_scores(&self) -> HashMap<u64, u64> {
+        let mut scores: HashMap<u64, u64> = self.members.iter().map(|m| (m.id, 0)).collect();
+        let member_count = self.members.len() as u64;
+
+        for day in all_days() {
+            for part in [1, 2] {
+                let mut finishers: Vec<(u64, u64)> = self
+                    .members
+                    .iter()
+                    .filter_map(|m| m.completion(day, part).map(|c| (c.timestamp, m.id)))
+                    .collect();
+
+                // ties are broken by member id, as the site does not expose sub-second timestamps.
+                finishers.sort_unstable();
+
+                for (rank, (_, id)) in finishers.into_iter().enumerate() {
+                    *scores.entry(id).or_default() += member_count - rank as u64;
+                }
+            }
+        }
+
+        scores
+    }
+}
+
+impl Member {
+    /// The name displayed on the leaderboard.
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    pub fn completion(&self, day: Day, part: u8) -> Option<&Completion> {
+        self.completions
+            .iter()
+            .find(|c| c.day == day && c.part == part)
+    }
+}
+
+/// Unix timestamp (in seconds) of when the puzzle for `day` unlocked.
+pub fn unlock_timestamp(year: u16, day: Day) -> u64 {
+    days_from_civil(year, 12, day.into_inner()) * 86400 + UNLOCK_UTC_OFFSET_SECS
+}
+
+/// Days since the unix epoch of a (post-1970) date in the proleptic gregorian calendar.
+/// See: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: u16, month: u8, day: u8) -> u64 {
+    let (year, month, day) = (u64::from(year), u64::from(month), u64::from(day));
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Formats a duration in seconds as `HH:MM:SS`, prefixed with the number of days if needed.
+pub fn format_duration(secs: u64) -> String {
+    let (days, secs) = (secs / 86400, secs % 86400);
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    );
+
+    if days > 0 {
+        format!("{days}d {time}")
+    } else {
+        time
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn get_u64(json: &HashMap<String, JsonValue>, key: &str) -> Option<u64> {
+    json.get(key)
+        .and_then(|v| v.get::<f64>())
+        .map(|x| *x as u64)
+}
+
+/// Ids are numbers in the member objects, but strings when used as keys or for the owner in some events.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn get_id(json: &HashMap<String, JsonValue>, key: &str) -> Option<u64> {
+    let value = json.get(key)?;
+    value
+        .get::<f64>()
+        .map(|x| *x as u64)
+        .or_else(|| value.get::<String>().and_then(|s| s.parse().ok()))
+}
+
+impl TryFrom<String> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let owner_id = get_id(json, "owner_id").ok_or("expected `owner_id` to be a number.")?;
+
+        let event = json
+            .get("event")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .ok_or("expected `event` to be a string.")?;
+
+        let mut members = json
+            .get("members")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected `members` to be an object.")?
+            .values()
+            .map(Member::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        members.sort_unstable_by_key(|m| m.id);
+
+        Ok(Leaderboard {
+            owner_id,
+            event,
+            members,
+        })
+    }
+}
+
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected member to be a JSON object.")?;
+
+        let id = get_id(json, "id").ok_or("Expected member.id to be a number.")?;
+
+        let name = json.get("name").and_then(|v| v.get::<String>()).cloned();
+
+        let local_score =
+            get_u64(json, "local_score").ok_or("Expected member.local_score to be a number.")?;
+
+        let stars = get_u64(json, "stars").ok_or("Expected member.stars to be a number.")?;
+
+        let days = json
+            .get("completion_day_level")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("Expected member.completion_day_level to be an object.")?;
+
+        let mut completions = vec![];
+
+        for (day, parts) in days {
+            let day = Day::from_str(day).map_err(|e| format!("Invalid completion day: {e}"))?;
+
+            let parts = parts
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected completion day to be an object.")?;
+
+            for (part, star) in parts {
+                let part = part
+                    .parse::<u8>()
+                    .map_err(|_| "Expected completion part to be a number.")?;
+
+                let timestamp = star
+                    .get::<HashMap<String, JsonValue>>()
+                    .and_then(|star| get_u64(star, "get_star_ts"))
+                    .ok_or("Expected completion to have a `get_star_ts` number.")?;
+
+                completions.push(Completion {
+                    day,
+                    part,
+                    timestamp,
+                });
+            }
+        }
+
+        completions.sort_unstable_by_key(|c| (c.day, c.part));
+
+        Ok(Member {
+            id,
+            name,
+            local_score,
+            stars,
+            completions,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+pub mod tests {
+    use super::{days_from_civil, format_duration, unlock_timestamp, Leaderboard};
+    use crate::day;
+
+    pub fn get_mock_leaderboard() -> Leaderboard {
+        let json = r#"{
+            "owner_id": 1,
+            "event": "2023",
+            "members": {
+                "1": {
+                    "id": 1, "name": "Alice", "local_score": 6, "stars": 3, "global_score": 0, "last_star_ts": 1701417900,
+                    "completion_day_level": {
+                        "1": { "1": { "get_star_ts": 1701407000, "star_index": 0 }, "2": { "get_star_ts": 1701407600, "star_index": 1 } },
+                        "2": { "1": { "get_star_ts": 1701493500, "star_index": 2 } }
+                    }
+                },
+                "2": {
+                    "id": 2, "name": null, "local_score": 2, "stars": 2, "global_score": 0, "last_star_ts": 1701410000,
+                    "completion_day_level": {
+                        "1": { "1": { "get_star_ts": 1701408000, "star_index": 0 }, "2": { "get_star_ts": 1701410000, "star_index": 1 } }
+                    }
+                }
+            }
+        }"#;
+
+        Leaderboard::try_from(json.to_string()).unwrap()
+    }
+
+    #[test]
+    fn parses_leaderboard() {
+        let leaderboard = get_mock_leaderboard();
+        assert_eq!(leaderboard.owner_id, 1);
+        assert_eq!(leaderboard.event, "2023");
+        assert_eq!(leaderboard.members.len(), 2);
+
+        let alice = leaderboard.member(1).unwrap();
+        assert_eq!(alice.display_name(), "Alice");
+        assert_eq!(alice.completions.len(), 3);
+        assert_eq!(alice.completion(day!(1), 2).unwrap().timestamp, 1701407600);
+        assert!(alice.completion(day!(2), 2).is_none());
+    }
+
+    #[test]
+    fn handles_anonymous_members() {
+        let leaderboard = get_mock_leaderboard();
+        assert_eq!(
+            leaderboard.member(2).unwrap().display_name(),
+            "(anonymous user #2)"
+        );
+    }
+
+    #[test]
+    fn recomputes_local_scores() {
+        let scores = get_mock_leaderboard().local_scores();
+        // day 1: alice finished both parts first (2 + 2), bob second (1 + 1). day 2: only alice (2).
+        assert_eq!(scores[&1], 6);
+        assert_eq!(scores[&2], 2);
+
+        // the fixture reports the same scores as the site would.
+        let leaderboard = get_mock_leaderboard();
+        assert!(leaderboard
+            .members
+            .iter()
+            .all(|m| scores[&m.id] == m.local_score));
+    }
+
+    #[test]
+    fn computes_unlock_timestamps() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        // 2023-12-01T05:00:00Z
+        assert_eq!(unlock_timestamp(2023, day!(1)), 1701406800);
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(0), "00:00:00");
+        assert_eq!(format_duration(3723), "01:02:03");
+        assert_eq!(format_duration(90000), "1d 01:00:00");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_missing_members() {
+        Leaderboard::try_from(r#"{ "owner_id": 1, "event": "2023" }"#.to_string()).unwrap();
+    }
+}
@@ -1,22 +1,38 @@
 use std::ffi::OsStr;
-use std::path::{Path, PathBuf};
-use std::{env, fs};
+use std::fs;
+use std::path::PathBuf;
 
 pub mod aoc_cli;
+pub mod bench_env;
 pub mod commands;
+pub mod input_check;
+pub mod leaderboard;
+pub mod perf;
 pub mod runner;
+pub mod workspace;
 
 use anyhow::Context;
 pub use day::*;
 
+mod allocations;
 mod day;
+mod encryption;
+mod html_report;
+mod module_template;
+mod puzzle;
 mod readme_benchmarks;
+mod readme_stars;
 mod run_multi;
+mod scaffold_log;
+mod submissions;
+mod svg_chart;
 mod timings;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+/// Clears the rest of the line, e.g. leftovers of an overwritten intermediate result.
+pub const ANSI_CLEAR_LINE: &str = "\x1b[K";
 
 #[derive(Debug, Clone, Copy)]
 pub enum DataFolder {
@@ -26,12 +42,12 @@ pub enum DataFolder {
 }
 
 impl DataFolder {
-    /// Returns the **relative** path of the data folder in `self`. It's relative to the package root.
-    fn sub_directory(self) -> &'static Path {
-        Path::new(match self {
-            Self::Examples => "./data/examples",
-            Self::Inputs => "./data/inputs",
-            Self::Puzzles => "./data/puzzles",
+    /// Returns the **relative** path of the data folder in `self`. It's relative to the workspace root.
+    fn sub_directory(self) -> PathBuf {
+        workspace::data_dir().join(match self {
+            Self::Examples => "examples",
+            Self::Inputs => "inputs",
+            Self::Puzzles => "puzzles",
         })
     }
 
@@ -46,7 +62,7 @@ impl DataFolder {
 
     /// Constructs the absolute path to the data folder in `self`.
     fn data_path(self) -> PathBuf {
-        Path::new(env!("CARGO_MANIFEST_DIR")).join(self.sub_directory())
+        workspace::absolute(self.sub_directory())
     }
 
     /// Constructs the **relative** path to the specified data file in this data folder.
@@ -91,10 +107,19 @@ impl From<Day> for DataFile {
     }
 }
 
-/// Helper to read a given data file from a given data folder
+/// Helper to read a given data file from a given data folder.
+/// Encrypted files are decrypted transparently.
 pub fn read_data_file(folder: DataFolder, file: DataFile) -> anyhow::Result<String> {
-    fs::read_to_string(folder.data_path_with(file))
-        .with_context(|| format!("couldn't get data from path '{:?}'", folder.path_with(file)))
+    let path = folder.data_path_with(file);
+    let contents = fs::read_to_string(&path)
+        .with_context(|| format!("couldn't get data from path '{:?}'", folder.path_with(file)))?;
+
+    encryption::decrypt_if_encrypted(contents, &path).with_context(|| {
+        format!(
+            "couldn't decrypt data from path '{:?}'",
+            folder.path_with(file)
+        )
+    })
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
@@ -125,7 +150,9 @@ macro_rules! solution {
 
         fn main() -> anyhow::Result<()> {
             use $crate::template::runner::*;
-            let input = $crate::template::read_data_file($crate::template::DataFolder::Inputs, $crate::template::DataFile::Day(DAY))?;
+            let source = InputSource::from_args()?;
+            let input = source.read(DAY)?;
+            let input = $crate::template::input_check::prepare(&input, &source, DAY)?;
             $( let _ = run_part($func, &input, DAY, $part); )*
 
             Ok(())
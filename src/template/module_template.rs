@@ -0,0 +1,263 @@
+/// Module that renders the solution file of a new day from a template.
+/// Built-in templates are compiled into the binary, and can be overridden or extended by
+/// adding a `templates/<name>.rs` file to the repository root.
+use std::{
+    collections::BTreeSet,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::Day;
+
+pub static DEFAULT_TEMPLATE: &str = "default";
+static USER_TEMPLATES_DIR: &str = "templates";
+static USER_TEMPLATES_EXTENSION: &str = "rs";
+
+static BUILTIN_TEMPLATES: [(&str, &str); 4] = [
+    (
+        "default",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/default.txt"
+        )),
+    ),
+    (
+        "parse-then-solve",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/parse-then-solve.txt"
+        )),
+    ),
+    (
+        "grid",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/grid.txt"
+        )),
+    ),
+    (
+        "single-part",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/single-part.txt"
+        )),
+    ),
+];
+
+#[derive(Debug)]
+pub enum Error {
+    NotFound {
+        name: String,
+        available: Vec<String>,
+    },
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NotFound { name, available } => write!(
+                f,
+                "template \"{name}\" does not exist. Available templates: {}.",
+                available.join(", ")
+            ),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Values substituted into a template. Placeholders are written as `%NAME%`:
+///
+/// - `%DAY_NUMBER%`: the day without padding, e.g. `5`.
+/// - `%DAY_PADDED%`: the day padded to two digits, e.g. `05`.
+/// - `%YEAR%`: the year set in `AOC_YEAR`. If it is not set, lines using it are left out.
+/// - `%PUZZLE_TITLE%`: the title of the puzzle, if its description was downloaded.
+/// - `%PART_ONE_ANSWER%` / `%PART_TWO_ANSWER%`: the expected answers for the examples, as a rust literal.
+#[derive(Clone, Debug)]
+pub struct Placeholders {
+    pub day: Day,
+    pub year: Option<u16>,
+    pub title: Option<String>,
+    pub answers: [Option<String>; 2],
+}
+
+impl Placeholders {
+    pub fn render(&self, template: &str) -> String {
+        // unknown answers are rendered as `todo!()`, so generated tests compile but point out what is missing.
+        let answer = |part: usize| match &self.answers[part - 1] {
+            Some(answer) if is_integer(answer) => answer.clone(),
+            Some(answer) => format!("{answer:?}"),
+            None => format!("todo!(\"example answer for part {part}\")"),
+        };
+
+        let template = match self.year {
+            Some(year) => template.replace("%YEAR%", &year.to_string()),
+            None => without_year(template),
+        };
+
+        template
+            .replace("%DAY_NUMBER%", &self.day.into_inner().to_string())
+            .replace("%DAY_PADDED%", &self.day.to_string())
+            .replace(
+                "%PUZZLE_TITLE%",
+                self.title.as_deref().unwrap_or("Untitled"),
+            )
+            .replace("%PART_ONE_ANSWER%", &answer(1))
+            .replace("%PART_TWO_ANSWER%", &answer(2))
+    }
+}
+
+/// Removes the lines using `%YEAR%`, e.g. the link to the puzzle, together with an empty doc comment line
+/// that separated them from the preceding docs.
+fn without_year(template: &str) -> String {
+    let mut lines: Vec<&str> = vec![];
+
+    for line in template.split_inclusive('\n') {
+        if !line.contains("%YEAR%") {
+            lines.push(line);
+        } else if lines.last().is_some_and(|l| l.trim() == "//!") {
+            lines.pop();
+        }
+    }
+
+    lines.concat()
+}
+
+fn is_integer(s: &str) -> bool {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+fn user_templates_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(USER_TEMPLATES_DIR)
+}
+
+fn user_template_path(name: &str) -> PathBuf {
+    user_templates_dir()
+        .join(name)
+        .with_extension(USER_TEMPLATES_EXTENSION)
+}
+
+/// Names of all templates, built-in and user-defined.
+pub fn available() -> Vec<String> {
+    let mut names: BTreeSet<String> = BUILTIN_TEMPLATES
+        .iter()
+        .map(|(name, _)| (*name).to_string())
+        .collect();
+
+    if let Ok(entries) = fs::read_dir(user_templates_dir()) {
+        for path in entries.filter_map(|entry| Some(entry.ok()?.path())) {
+            if path
+                .extension()
+                .is_some_and(|e| e == USER_TEMPLATES_EXTENSION)
+            {
+                if let Some(stem) = path.file_stem() {
+                    names.insert(stem.to_string_lossy().into());
+                }
+            }
+        }
+    }
+
+    names.into_iter().collect()
+}
+
+/// Load a template by name. A user-defined template takes precedence over a built-in one of the same name.
+pub fn load(name: &str) -> Result<String, Error> {
+    let path = user_template_path(name);
+
+    if path.is_file() {
+        return Ok(fs::read_to_string(path)?);
+    }
+
+    BUILTIN_TEMPLATES
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, template)| (*template).to_string())
+        .ok_or_else(|| Error::NotFound {
+            name: name.into(),
+            available: available(),
+        })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{available, load, Placeholders, BUILTIN_TEMPLATES};
+    use crate::day;
+
+    fn placeholders() -> Placeholders {
+        Placeholders {
+            day: day!(5),
+            year: None,
+            title: None,
+            answers: [None, None],
+        }
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let placeholders = Placeholders {
+            day: day!(5),
+            year: Some(2023),
+            title: Some("If You Give A Seed A Fertilizer".into()),
+            answers: [Some("35".into()), Some("46".into())],
+        };
+
+        let rendered = placeholders.render(
+            "%YEAR% %DAY_NUMBER% %DAY_PADDED% %PUZZLE_TITLE% %PART_ONE_ANSWER% %PART_TWO_ANSWER%",
+        );
+        assert_eq!(rendered, "2023 5 05 If You Give A Seed A Fertilizer 35 46");
+    }
+
+    #[test]
+    fn leaves_out_link_without_year() {
+        let template = "//! Day %DAY_NUMBER%\n//!\n//! <https://adventofcode.com/%YEAR%/day/%DAY_NUMBER%>\n\nuse std::fmt;\n";
+        assert_eq!(
+            placeholders().render(template),
+            "//! Day 5\n\nuse std::fmt;\n"
+        );
+    }
+
+    #[test]
+    fn renders_text_answers_as_string() {
+        let placeholders = Placeholders {
+            answers: [Some("-12".into()), Some("CMZ".into())],
+            ..placeholders()
+        };
+
+        let rendered = placeholders.render("%PART_ONE_ANSWER% %PART_TWO_ANSWER%");
+        assert_eq!(rendered, "-12 \"CMZ\"");
+    }
+
+    #[test]
+    fn renders_missing_answers_as_todo() {
+        let rendered = placeholders().render("%PART_TWO_ANSWER%");
+        assert_eq!(rendered, "todo!(\"example answer for part 2\")");
+    }
+
+    #[test]
+    fn builtin_templates_use_day_number() {
+        for (_, template) in BUILTIN_TEMPLATES {
+            assert!(template.contains("advent_of_code::solution!(%DAY_NUMBER%"));
+        }
+    }
+
+    #[test]
+    fn lists_builtin_templates() {
+        let names = available();
+        for (name, _) in BUILTIN_TEMPLATES {
+            assert!(names.iter().any(|n| n == name));
+        }
+    }
+
+    #[test]
+    fn errors_for_unknown_template() {
+        assert!(load("does-not-exist").is_err());
+    }
+}
@@ -0,0 +1,86 @@
+/// Wrapper module around the "perf" command-line.
+use std::{
+    ffi::OsStr,
+    fmt::Display,
+    path::Path,
+    process::{Command, Output, Stdio},
+};
+
+/// Sampling frequency passed to `perf record`. Deliberately not a round number to avoid lockstep sampling.
+const SAMPLE_FREQUENCY: &str = "999";
+
+#[derive(Debug)]
+pub enum PerfCommandError {
+    CommandNotFound,
+    CommandNotCallable,
+    BadExitStatus(Output),
+}
+
+impl Display for PerfCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PerfCommandError::CommandNotFound => write!(f, "perf is not present in environment."),
+            PerfCommandError::CommandNotCallable => write!(f, "perf could not be called."),
+            PerfCommandError::BadExitStatus(_) => {
+                write!(f, "perf exited with a non-zero status.")
+            }
+        }
+    }
+}
+
+pub fn check() -> Result<(), PerfCommandError> {
+    Command::new("perf")
+        .arg("--version")
+        .output()
+        .map_err(|_| PerfCommandError::CommandNotFound)?;
+    Ok(())
+}
+
+/// Sample the call stacks of `program` (invoked with `args`) and write them to `output`.
+pub fn record(
+    output: &Path,
+    program: &Path,
+    args: impl IntoIterator<Item = impl AsRef<OsStr>>,
+) -> Result<Output, PerfCommandError> {
+    let mut cmd = Command::new("perf");
+    cmd.args([
+        "record",
+        "--freq",
+        SAMPLE_FREQUENCY,
+        "--call-graph",
+        "dwarf",
+    ])
+    .arg("--output")
+    .arg(output)
+    .arg("--")
+    .arg(program)
+    .args(args)
+    .stdout(Stdio::inherit())
+    .stderr(Stdio::inherit());
+
+    call_perf(cmd)
+}
+
+/// Dump the samples in `input` as text. The result is captured in [`Output::stdout`].
+pub fn script(input: &Path) -> Result<Output, PerfCommandError> {
+    let mut cmd = Command::new("perf");
+    cmd.arg("script")
+        .arg("--input")
+        .arg(input)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit());
+
+    call_perf(cmd)
+}
+
+fn call_perf(mut cmd: Command) -> Result<Output, PerfCommandError> {
+    let output = cmd
+        .output()
+        .map_err(|_| PerfCommandError::CommandNotCallable)?;
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(PerfCommandError::BadExitStatus(output))
+    }
+}
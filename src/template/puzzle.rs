@@ -0,0 +1,221 @@
+/// Module that extracts information from puzzle descriptions downloaded via `cargo download <day>`.
+/// Descriptions are markdown, the parsing here is a best effort that works with how puzzles are usually phrased.
+use crate::template::{read_data_file, DataFile, DataFolder, Day};
+
+static PART_TWO_HEADING: &str = "--- Part Two ---";
+static CODE_FENCE: &str = "```";
+
+/// The example of a puzzle part, as far as it could be extracted.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Example {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// Read the description of a day's puzzle, if it was downloaded.
+pub fn read(day: Day) -> Option<String> {
+    read_data_file(DataFolder::Puzzles, DataFile::Day(day)).ok()
+}
+
+/// Extract the title from a puzzle description.
+/// Descriptions start with a heading like `## --- Day 1: Trebuchet?! ---`, this returns `Trebuchet?!`.
+pub fn title(description: &str) -> Option<String> {
+    description.lines().find_map(|line| {
+        let heading = line
+            .trim_start_matches(['#', '\\'])
+            .trim()
+            .strip_prefix("--- Day ")?
+            .strip_suffix(" ---")?;
+
+        let (_, title) = heading.split_once(": ")?;
+        Some(title.trim().to_string()).filter(|title| !title.is_empty())
+    })
+}
+
+/// Extract the examples of both parts from a puzzle description.
+///
+/// The example input is the first code block after a line mentioning "for example", or the first code block of a part.
+/// Part two often reuses the example of part one, in which case that input is used for both.
+/// The answer is the last emphasized inline code value of a part, which is how puzzles highlight results.
+pub fn examples(description: &str) -> [Example; 2] {
+    let (part_one, part_two) = match description.find(PART_TWO_HEADING) {
+        Some(pos) => (&description[..pos], Some(&description[pos..])),
+        None => (description, None),
+    };
+
+    let part_one = example(part_one);
+
+    let part_two = part_two
+        .map(example)
+        .map_or_else(Example::default, |e| Example {
+            input: e.input.or_else(|| part_one.input.clone()),
+            answer: e.answer,
+        });
+
+    [part_one, part_two]
+}
+
+fn example(section: &str) -> Example {
+    let mut blocks: Vec<(bool, String)> = vec![];
+    let mut answer = None;
+
+    let mut block: Option<Vec<&str>> = None;
+    let mut after_for_example = false;
+
+    for line in section.lines() {
+        if line.trim_start().starts_with(CODE_FENCE) {
+            match block.take() {
+                Some(lines) => blocks.push((after_for_example, code_block(&lines))),
+                None => block = Some(vec![]),
+            }
+            continue;
+        }
+
+        match block.as_mut() {
+            Some(lines) => lines.push(line),
+            None => {
+                if line.to_lowercase().contains("for example") {
+                    after_for_example = true;
+                }
+                if let Some(value) = emphasized_code(line).pop() {
+                    answer = Some(value);
+                }
+            }
+        }
+    }
+
+    let input = blocks
+        .iter()
+        .find(|(after_for_example, _)| *after_for_example)
+        .or(blocks.first())
+        .map(|(_, input)| input.clone());
+
+    Example { input, answer }
+}
+
+fn code_block(lines: &[&str]) -> String {
+    let mut input = lines.join("\n").trim_end().to_string();
+    input.push('\n');
+    input
+}
+
+/// Values of inline code in a line that is emphasized, e.g. `` `*142*` `` or ``*`142`*``.
+fn emphasized_code(line: &str) -> Vec<String> {
+    let segments: Vec<&str> = line.split('`').collect();
+
+    // every odd segment is the content of a code span, as long as it is closed.
+    (1..segments.len().saturating_sub(1))
+        .step_by(2)
+        .filter_map(|i| {
+            let code = segments[i];
+            let value = code.trim_matches('*');
+
+            let emphasized_inside = value.len() < code.len();
+            let emphasized_outside =
+                segments[i - 1].ends_with('*') && segments[i + 1].starts_with('*');
+
+            (!value.is_empty() && (emphasized_inside || emphasized_outside))
+                .then(|| value.to_string())
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{emphasized_code, examples, title, Example};
+
+    const DESCRIPTION: &str = "\\--- Day 1: Trebuchet?! ---
+----------
+
+Something is wrong with global snow production.
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+
+```
+
+In this example, the calibration values are `12` and `38`. Adding these together produces `*50*`.
+
+\\--- Part Two ---
+----------
+
+Your calculation isn't quite right. For example:
+
+```
+two1nine
+eightwothree
+```
+
+Adding these together produces `*112*`.
+";
+
+    #[test]
+    fn extracts_title() {
+        let description =
+            "\n## --- Day 1: Trebuchet?! ---\nSomething is wrong with global snow production.";
+        assert_eq!(title(description).as_deref(), Some("Trebuchet?!"));
+    }
+
+    #[test]
+    fn extracts_title_with_colon() {
+        let description = "## --- Day 24: Never Tell Me The Odds: Part 1 ---";
+        assert_eq!(
+            title(description).as_deref(),
+            Some("Never Tell Me The Odds: Part 1")
+        );
+    }
+
+    #[test]
+    fn extracts_escaped_title() {
+        assert_eq!(title(DESCRIPTION).as_deref(), Some("Trebuchet?!"));
+    }
+
+    #[test]
+    fn ignores_missing_title() {
+        assert_eq!(title("# Some notes\n--- not a title ---"), None);
+    }
+
+    #[test]
+    fn extracts_examples() {
+        let [part_one, part_two] = examples(DESCRIPTION);
+        assert_eq!(
+            part_one,
+            Example {
+                input: Some("1abc2\npqr3stu8vwx\n".into()),
+                answer: Some("50".into())
+            }
+        );
+        assert_eq!(
+            part_two,
+            Example {
+                input: Some("two1nine\neightwothree\n".into()),
+                answer: Some("112".into())
+            }
+        );
+    }
+
+    #[test]
+    fn reuses_example_of_part_one() {
+        let description = "For example:\n```\n1\n2\n```\nThis produces `*3*`.\n--- Part Two ---\nNow it produces *`6`*.";
+        let [part_one, part_two] = examples(description);
+        assert_eq!(part_two.input, part_one.input);
+        assert_eq!(part_two.answer.as_deref(), Some("6"));
+    }
+
+    #[test]
+    fn ignores_missing_part_two() {
+        let [_, part_two] = examples("For example:\n```\n1\n```\nThis produces `*1*`.");
+        assert_eq!(part_two, Example::default());
+    }
+
+    #[test]
+    fn finds_emphasized_code() {
+        assert_eq!(
+            emphasized_code("`1` and `*2*`, *`3`* but not *4* or `5"),
+            vec!["2".to_string(), "3".to_string()]
+        );
+    }
+}
@@ -1,11 +1,14 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fmt::Display, fs, io};
 
+use crate::template::svg_chart;
 use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::workspace;
 
-static MARKER: &str = "<!--- benchmarking table --->";
+pub static MARKER: &str = "<!--- benchmarking table --->";
+/// Path of the chart relative to the workspace root, as linked from the readme.
+static CHART_PATH: &str = "./.assets/benchmarks.svg";
 
 #[derive(Debug)]
 pub enum Error {
@@ -19,55 +22,110 @@ impl From<std::io::Error> for Error {
     }
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+/// Byte offsets of all occurences of `marker` that are not quoted as code.
+/// This way, prose that documents a marker (e.g. in an inline code span or a fenced code block) is not mistaken for it.
+fn marker_positions(readme: &str, marker: &str) -> Vec<usize> {
+    let mut positions = vec![];
+    let mut in_fence = false;
+    let mut offset = 0;
+
+    for line in readme.split_inclusive('\n') {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        } else if !in_fence {
+            for (i, _) in line.match_indices(marker) {
+                if line[..i].matches('`').count() % 2 == 0 {
+                    positions.push(offset + i);
+                }
+            }
+        }
+
+        offset += line.len();
+    }
+
+    positions
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches = marker_positions(readme, marker);
 
     if matches.len() > 2 {
         return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
+            "too many occurences of marker in README.".into(),
         ));
     }
 
     let pos_start = matches
         .first()
-        .map(|m| m.0)
+        .copied()
         .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
 
     let pos_end = matches
         .last()
-        .map(|m| m.0 + m.1.len())
+        .map(|pos| pos + marker.len())
         .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
 
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+/// Check that `readme` can be updated with a table delimited by `marker`.
+/// A single marker is valid as well, it is replaced by the table on the first update.
+pub fn check_markers(readme: &str, marker: &str) -> Result<(), Error> {
+    match marker_positions(readme, marker).len() {
+        1 | 2 => Ok(()),
+        0 => Err(Error::Parser("marker is missing in README.".into())),
+        n => Err(Error::Parser(format!(
+            "too many occurences of marker in README, found {n}."
+        ))),
+    }
+}
+
+/// Replace the section between two occurences of `marker` with `table`.
+/// `table` is expected to start and end with `marker` itself, so that subsequent updates find it again.
+pub fn replace_table(readme: &mut String, marker: &str, table: &str) -> Result<(), Error> {
+    let positions = locate_table(readme, marker)?;
+    readme.replace_range(positions.pos_start..positions.pos_end, table);
+    Ok(())
+}
+
+fn construct_table(
+    prefix: &str,
+    timings: Timings,
+    total_millis: f64,
+    chart_path: Option<&str>,
+) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+
+    if let Some(chart_path) = chart_path {
+        lines.push(format!("![Benchmarks chart]({chart_path})"));
+        lines.push(String::new());
+    }
+
+    lines.push("| Day | Part 1 | Part 2 |".into());
+    lines.push("| :---: | :---: | :---:  |".into());
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = workspace::bin_path(timing.day);
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}](./{}) | `{}` | `{}` |",
             timing.day.into_inner(),
-            path,
+            path.display(),
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
         ));
@@ -80,25 +138,43 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
-    Ok(())
+fn update_content(
+    s: &mut String,
+    timings: Timings,
+    total_millis: f64,
+    chart_path: Option<&str>,
+) -> Result<(), Error> {
+    let table = construct_table("##", timings, total_millis, chart_path);
+    replace_table(s, MARKER, &table)
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+/// Update the benchmarking table in the readme.
+/// If `with_chart` is set, an SVG chart of the timings is written to `.assets/` and embedded above the table.
+/// Otherwise, a chart written by a previous update is deleted, since it no longer matches the table.
+pub fn update(timings: Timings, with_chart: bool) -> Result<(), Error> {
+    let path = workspace::absolute(workspace::readme_path());
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    let chart_file = workspace::absolute(CHART_PATH);
+
+    let chart_path = if with_chart {
+        fs::write(&chart_file, svg_chart::render_parts_chart(&timings))?;
+        Some(CHART_PATH)
+    } else {
+        match fs::remove_file(&chart_file) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+            _ => None,
+        }
+    };
+
+    update_content(&mut readme, timings, total_millis, chart_path)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
+    use super::{check_markers, update_content, MARKER};
     use crate::{day, template::timings::Timing, template::timings::Timings};
 
     fn get_mock_timings() -> Timings {
@@ -109,18 +185,21 @@ mod tests {
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    conditions: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    conditions: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
+                    conditions: None,
                 },
             ],
         }
@@ -130,36 +209,53 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
+    }
+
+    #[test]
+    fn checks_markers() {
+        assert!(check_markers(&format!("foo\n{}\n{}", MARKER, MARKER), MARKER).is_ok());
+        assert!(check_markers(&format!("foo\n{}", MARKER), MARKER).is_ok());
+        assert!(check_markers(&format!("{} {} {}", MARKER, MARKER, MARKER), MARKER).is_err());
+        assert!(check_markers("foo", MARKER).is_err());
+        assert!(check_markers(&format!("use `{}`", MARKER), MARKER).is_err());
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
 
+    #[test]
+    fn embeds_chart() {
+        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, get_mock_timings(), 190.0, Some("./chart.svg")).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Some("./chart.svg")).unwrap();
+        assert_eq!(s.matches("![Benchmarks chart](./chart.svg)").count(), 1);
+    }
+
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -168,9 +264,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./solutions/src/bin/01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./solutions/src/bin/02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./solutions/src/bin/04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
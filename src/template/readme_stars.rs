@@ -0,0 +1,209 @@
+/// Module that updates the readme with advent of code progress.
+/// This renders the same table as the `aoc-readme-stars` action, without leaving the repository.
+use std::{collections::BTreeMap, fs};
+
+use crate::template::leaderboard::Member;
+use crate::template::readme_benchmarks::{replace_table, Error};
+use crate::template::submissions::Submissions;
+use crate::template::{all_days, workspace, Day};
+
+pub static MARKER: &str = "<!--- advent_readme_stars table --->";
+static STAR: &str = "⭐";
+
+/// The parts solved per day.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stars {
+    days: BTreeMap<Day, [bool; 2]>,
+}
+
+impl Stars {
+    /// Collect stars for all parts that had an answer accepted.
+    pub fn from_submissions(submissions: &Submissions) -> Self {
+        let mut stars = Self::default();
+
+        for day in all_days() {
+            for part in [1, 2] {
+                if submissions.correct_answer(day, part).is_some() {
+                    stars.insert(day, part);
+                }
+            }
+        }
+
+        stars
+    }
+
+    /// Collect stars of a single leaderboard member.
+    pub fn from_member(member: &Member) -> Self {
+        let mut stars = Self::default();
+
+        for completion in &member.completions {
+            stars.insert(completion.day, completion.part);
+        }
+
+        stars
+    }
+
+    fn insert(&mut self, day: Day, part: u8) {
+        if let Some(solved) = part
+            .checked_sub(1)
+            .and_then(|i| self.days.entry(day).or_default().get_mut(usize::from(i)))
+        {
+            *solved = true;
+        }
+    }
+
+    /// Combine two sets of stars, keeping a star if either has it.
+    pub fn merge(&self, other: &Self) -> Self {
+        let mut days = self.days.clone();
+
+        for (day, parts) in &other.days {
+            let entry = days.entry(*day).or_default();
+            entry[0] |= parts[0];
+            entry[1] |= parts[1];
+        }
+
+        Self { days }
+    }
+
+    pub fn total(&self) -> usize {
+        self.days
+            .values()
+            .flatten()
+            .filter(|solved| **solved)
+            .count()
+    }
+}
+
+fn construct_table(prefix: &str, stars: &Stars, year: Option<u16>) -> String {
+    let header = match year {
+        Some(year) => format!("{prefix} {year} Results"),
+        None => format!("{prefix} Results"),
+    };
+
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        header,
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    for (day, parts) in &stars.days {
+        let star = |solved: bool| if solved { STAR } else { " " };
+        let day_label = match year {
+            Some(year) => format!(
+                "[Day {}](https://adventofcode.com/{year}/day/{})",
+                day.into_inner(),
+                day.into_inner()
+            ),
+            None => format!("Day {}", day.into_inner()),
+        };
+
+        lines.push(format!(
+            "| {day_label} | {} | {} |",
+            star(parts[0]),
+            star(parts[1])
+        ));
+    }
+
+    lines.push(MARKER.into());
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, stars: &Stars, year: Option<u16>) -> Result<(), Error> {
+    let table = construct_table("##", stars, year);
+    replace_table(s, MARKER, &table)
+}
+
+pub fn update(stars: &Stars, year: Option<u16>) -> Result<(), Error> {
+    let path = workspace::absolute(workspace::readme_path());
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
+    update_content(&mut readme, stars, year)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{update_content, Stars, MARKER};
+    use crate::{
+        day,
+        template::{
+            leaderboard::tests::get_mock_leaderboard,
+            submissions::{Submission, Submissions},
+        },
+    };
+
+    fn get_mock_submissions() -> Submissions {
+        let submission = |day, part, correct| Submission {
+            day,
+            part,
+            answer: "42".into(),
+            correct,
+            timestamp: 0,
+        };
+
+        Submissions {
+            data: vec![
+                submission(day!(1), 1, Some(true)),
+                submission(day!(1), 2, Some(false)),
+                submission(day!(3), 1, Some(true)),
+                submission(day!(3), 2, Some(true)),
+                submission(day!(4), 1, None),
+            ],
+        }
+    }
+
+    #[test]
+    fn collects_stars_from_submissions() {
+        let stars = Stars::from_submissions(&get_mock_submissions());
+        assert_eq!(stars.total(), 3);
+    }
+
+    #[test]
+    fn merges_stars() {
+        let leaderboard = get_mock_leaderboard();
+        let from_member = Stars::from_member(leaderboard.member(1).unwrap());
+        let merged = Stars::from_submissions(&get_mock_submissions()).merge(&from_member);
+        assert_eq!(merged.total(), 5);
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\n{}\n{}\nbar", MARKER, MARKER);
+        let stars = Stars::from_submissions(&get_mock_submissions());
+        update_content(&mut s, &stars, Some(2023)).unwrap();
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2023 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2023/day/1) | ⭐ |   |",
+            "| [Day 3](https://adventofcode.com/2023/day/3) | ⭐ | ⭐ |",
+            "<!--- advent_readme_stars table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn ignores_marker_in_prose() {
+        let mut s = format!(
+            "{MARKER}\n{MARKER}\n\nProgress is rendered into the `{MARKER}` section.\n\n```md\n{MARKER}\n```\n"
+        );
+        update_content(&mut s, &Stars::default(), None).unwrap();
+        assert!(s.starts_with(&format!("{MARKER}\n## Results\n")));
+        assert!(s.contains(&format!("rendered into the `{MARKER}` section.")));
+        assert!(s.ends_with(&format!("```md\n{MARKER}\n```\n")));
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        update_content(&mut s, &Stars::default(), None).unwrap();
+    }
+}
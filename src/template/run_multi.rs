@@ -1,13 +1,20 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, fmt::Display, io};
 
 use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
+    bench_env::BenchConditions,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// Runs the solutions for `days_to_run`. When `conditions` are passed, they are recorded with each timing.
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    conditions: Option<&BenchConditions>,
+) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
@@ -24,12 +31,19 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let output = match child_commands::run_solution(day, is_timed, is_release) {
+                Ok(output) => output,
+                Err(e) => {
+                    eprintln!("Failed to run solution: {e}");
+                    return;
+                }
+            };
 
             if output.is_empty() {
                 println!("Not solved.");
             } else {
-                let val = child_commands::parse_exec_time(&output, day);
+                let mut val = child_commands::parse_exec_time(&output, day);
+                val.conditions = conditions.cloned();
                 timings.push(val);
             }
         });
@@ -58,39 +72,38 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "could not read output of solution."),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
 }
 
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::Error;
+    use crate::template::{
+        runner::InputSource, timings::parse_duration_nanos, workspace, Day, ANSI_BOLD, ANSI_RESET,
+    };
     use std::{
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        process::{Command, Output, Stdio},
         thread,
     };
 
     /// Run the solution bin for a given day
     pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !workspace::has_solution(day) {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec![
-            "run",
-            "--quiet",
-            "--bin",
-            &day_padded,
-            "--package",
-            "solutions",
-        ];
+        let bin_args = workspace::cargo_bin_args(day);
+        let mut args = vec!["run", "--quiet"];
+        args.extend(bin_args.iter().map(String::as_str));
 
         if is_release {
             args.push("--release");
@@ -134,12 +147,78 @@ pub mod child_commands {
         Ok(output)
     }
 
+    /// Run the solution bin for a given day against another input, capturing its output.
+    pub fn run_solution_with_input(
+        day: Day,
+        is_release: bool,
+        input: &InputSource,
+    ) -> Result<Output, Error> {
+        let mut args = vec!["run".to_string(), "--quiet".to_string()];
+        args.extend(workspace::cargo_bin_args(day));
+
+        if is_release {
+            args.push("--release".to_string());
+        }
+
+        args.push("--".to_string());
+        args.extend(input.to_args());
+
+        Ok(Command::new("cargo").args(&args).output()?)
+    }
+
+    /// Parse the answers printed by a solution. Parts that failed or did not run are `None`.
+    pub fn parse_answers(output: &[String]) -> [Option<String>; 2] {
+        let mut answers = [None, None];
+
+        let mut lines = output
+            .iter()
+            .map(|line| {
+                // intermediate results are overwritten by the final one via a carriage return.
+                let line = line.rsplit('\r').next().unwrap_or_default();
+                line.replace(ANSI_BOLD, "").replace(ANSI_RESET, "")
+            })
+            .peekable();
+
+        while let Some(line) = lines.next() {
+            let Some((part, result)) = line.split_once(": ") else {
+                continue;
+            };
+
+            let index = match part {
+                "Part 1" => 0,
+                "Part 2" => 1,
+                _ => continue,
+            };
+
+            if result.starts_with('✖') {
+                continue;
+            }
+
+            if result.starts_with('▼') {
+                // multi-line results are printed below the part, up to the next part.
+                let mut result_lines = vec![];
+                while let Some(next) = lines.next_if(|l| !l.starts_with("Part ")) {
+                    result_lines.push(next);
+                }
+                answers[index] = Some(result_lines.join("\n").trim_end().to_string());
+            } else {
+                let answer = result
+                    .rsplit_once(" (")
+                    .map_or(result, |(answer, _)| answer);
+                answers[index] = Some(answer.trim().to_string());
+            }
+        }
+
+        answers
+    }
+
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
         let mut timings = super::Timing {
             day,
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
+            conditions: None,
         };
 
         output
@@ -170,27 +249,17 @@ pub mod child_commands {
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
     fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
         let str_timing = line
             .split(" samples)")
             .next()?
             .split('(')
-            .last()?
+            .next_back()?
             .split('@')
             .next()?
             .trim();
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+        let parsed_timing = parse_duration_nanos(str_timing)?;
 
         Some((str_timing, parsed_timing))
     }
@@ -211,7 +280,7 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_answers, parse_exec_time};
 
         use crate::day;
 
@@ -259,5 +328,26 @@ pub mod child_commands {
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn parses_answers() {
+            let res = parse_answers(&[
+                "Line 'treb7uchet' has digits 7 and 7".into(),
+                "Part 1: \x1b[1m142\x1b[0m\rPart 1: \x1b[1m142\x1b[0m (34.6µs)".into(),
+                "Part 2: \x1b[1m281\x1b[0m (74.13ms @ 99999 samples)".into(),
+            ]);
+            assert_eq!(res, [Some("142".into()), Some("281".into())]);
+        }
+
+        #[test]
+        fn parses_multi_line_and_missing_answers() {
+            let res = parse_answers(&[
+                "Part 1: ✖ Error: not yet implemented".into(),
+                "Part 2: ▼ \rPart 2: ▼  (1.2ms)".into(),
+                "#..#".into(),
+                "####".into(),
+            ]);
+            assert_eq!(res, [None, Some("#..#\n####".into())]);
+        }
     }
 }
@@ -1,19 +1,135 @@
 /// Encapsulates code that interacts with solution functions.
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{stdout, Write};
+use std::io::{stdin, stdout, Write};
+use std::path::PathBuf;
 use std::process::Output;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, fs, io, process};
 
+use anyhow::{bail, Context};
+
+use crate::template::submissions::Submissions;
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+#[cfg(feature = "dhat-heap")]
+use crate::template::{allocations, workspace};
+use crate::template::{
+    aoc_cli, encryption, read_data_file, DataFile, DataFolder, Day, ANSI_CLEAR_LINE, ANSI_ITALIC,
+    ANSI_RESET,
+};
+
+/// Time a sampling profiler gets to collect stacks from a single part.
+const PROFILE_DURATION: Duration = Duration::from_secs(5);
+
+/// Where a solution reads its input from. Defaults to the puzzle input in `data/inputs`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum InputSource {
+    #[default]
+    Puzzle,
+    /// One of the examples in `data/examples`, e.g. `05-1.txt` for `Example(1)`.
+    Example(u8),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parse the input source from the arguments passed to the solution binary by `solve`.
+    pub fn from_args() -> anyhow::Result<Self> {
+        Self::parse(&env::args().collect::<Vec<_>>())
+    }
+
+    fn parse(args: &[String]) -> anyhow::Result<Self> {
+        let value_of = |flag: &str| -> anyhow::Result<Option<&String>> {
+            match args.iter().position(|x| x == flag) {
+                Some(index) => match args.get(index + 1) {
+                    Some(value) => Ok(Some(value)),
+                    None => bail!("missing value for `{flag}`."),
+                },
+                None => Ok(None),
+            }
+        };
+
+        let mut sources = vec![];
+
+        if let Some(part) = value_of("--example")? {
+            let Ok(part) = part.parse::<u8>() else {
+                bail!("`--example` expects the number of an example, got `{part}`.");
+            };
+            sources.push(InputSource::Example(part));
+        }
+
+        if let Some(path) = value_of("--input")? {
+            sources.push(InputSource::File(path.into()));
+        }
+
+        if args.iter().any(|x| x == "--stdin") {
+            sources.push(InputSource::Stdin);
+        }
+
+        match sources.len() {
+            0 => Ok(InputSource::Puzzle),
+            1 => Ok(sources.remove(0)),
+            _ => bail!("only one of `--example`, `--input` and `--stdin` can be used."),
+        }
+    }
+
+    /// The arguments that select this input source, to be passed on to the solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Puzzle => vec![],
+            InputSource::Example(part) => vec!["--example".into(), part.to_string()],
+            InputSource::File(path) => vec!["--input".into(), path.display().to_string()],
+            InputSource::Stdin => vec!["--stdin".into()],
+        }
+    }
+
+    /// Read the input for a day. Files are decrypted if needed, just like puzzle inputs.
+    pub fn read(&self, day: Day) -> anyhow::Result<String> {
+        match self {
+            InputSource::Puzzle => read_data_file(DataFolder::Inputs, DataFile::Day(day)),
+            InputSource::Example(part) => {
+                read_data_file(DataFolder::Examples, DataFile::DayPart(day, *part))
+            }
+            InputSource::File(path) => {
+                let contents = fs::read_to_string(path)
+                    .with_context(|| format!("couldn't read input from path '{path:?}'"))?;
+                encryption::decrypt_if_encrypted(contents, path)
+                    .with_context(|| format!("couldn't decrypt input from path '{path:?}'"))
+            }
+            InputSource::Stdin => {
+                io::read_to_string(stdin()).context("couldn't read input from stdin")
+            }
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Puzzle => write!(f, "puzzle input"),
+            InputSource::Example(part) => write!(f, "example {part}"),
+            InputSource::File(path) => write!(f, "input \"{}\"", path.display()),
+            InputSource::Stdin => write!(f, "input from stdin"),
+        }
+    }
+}
+
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> anyhow::Result<T>,
+    input: I,
+    day: Day,
+    part: u8,
+) -> anyhow::Result<()> {
+    if env::args().any(|x| x == "--profile") {
+        return profile_part(func, input, part);
+    }
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> anyhow::Result<T>, input: I, day: Day, part: u8) -> anyhow::Result<()> {
     let part_str = format!("Part {part}");
 
     let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+        run_timed(func, input, DataFile::DayPart(day, part), |result| {
+            print_result(result, &part_str, "")
+        });
 
     print_result(&result, &part_str, &format_duration(&duration, samples));
     submit_result(result?, day, part);
@@ -24,9 +140,13 @@ pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> anyhow::Result<T>, inp
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// With the `dhat-heap` feature, the heap profile of the first run is written to `data/profiles`, named after `profile_file`.
+#[cfg_attr(not(feature = "dhat-heap"), allow(unused_variables))]
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    profile_file: DataFile,
     hook: impl Fn(&T),
 ) -> (T, Duration, u128) {
     let timer = Instant::now();
@@ -34,7 +154,11 @@ fn run_timed<I: Clone, T>(
         let input = input.clone();
 
         #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
+        let _profiler = {
+            let path = allocations::profile_path(profile_file);
+            let _ = fs::create_dir_all(workspace::absolute(workspace::profiles_dir()));
+            dhat::Profiler::builder().file_name(path).build()
+        };
 
         func(input)
     };
@@ -57,13 +181,8 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -82,6 +201,45 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
     )
 }
 
+/// Run a solution part in a loop, so that a sampling profiler attached to the process (see `cargo profile`) collects enough samples.
+/// If `--part` is passed, other parts are skipped.
+fn profile_part<I: Clone, T>(
+    func: impl Fn(I) -> anyhow::Result<T>,
+    input: I,
+    part: u8,
+) -> anyhow::Result<()> {
+    let args: Vec<String> = env::args().collect();
+
+    if let Some(part_index) = args.iter().position(|x| x == "--part") {
+        let Some(Ok(part_profile)) = args.get(part_index + 1).map(|x| x.parse::<u8>()) else {
+            eprintln!("Unexpected command-line input. Format: cargo profile 1 --part 1");
+            process::exit(1);
+        };
+
+        if part_profile != part {
+            return Ok(());
+        }
+    }
+
+    let timer = Instant::now();
+    let mut iterations: u128 = 0;
+
+    while iterations == 0 || timer.elapsed() < PROFILE_DURATION {
+        // a failing solution would only leave a profile of the error path, so stop with a non-zero status instead.
+        if let Err(error) = black_box(func(black_box(input.clone()))) {
+            eprintln!("Part {part}: ✖ Error: {error:#}");
+            process::exit(1);
+        }
+        iterations += 1;
+    }
+
+    println!(
+        "Part {part}: profiled {iterations} iterations ({:.1?})",
+        timer.elapsed()
+    );
+    Ok(())
+}
+
 fn average_duration(numbers: &[Duration]) -> u128 {
     numbers
         .iter()
@@ -123,11 +281,26 @@ fn print_result<T: Display>(result: &anyhow::Result<T>, part: &str, duration_str
             }
         }
         Err(error) => {
+            // errors like the line errors of the solutions crate come with an excerpt of the input below the message.
+            let message = error.to_string();
+            let (summary, details) = message.split_once('\n').unwrap_or((&message, ""));
+            let details: Vec<&str> = details.lines().collect();
+
             if is_intermediate_result {
-                print!("{part}: ✖ Error: {error}");
+                print!("{part}: ✖ Error: {summary}");
+                for line in &details {
+                    print!("\n    {line}");
+                }
             } else {
+                // move back up to the first line of the intermediate result to overwrite all of it.
+                if !details.is_empty() {
+                    print!("\x1b[{}F", details.len());
+                }
                 print!("\r");
-                println!("{part}: ✖ Error: {error}");
+                println!("{part}: ✖ Error: {summary}{ANSI_CLEAR_LINE}");
+                for line in &details {
+                    println!("    {line}{ANSI_CLEAR_LINE}");
+                }
             }
         }
     }
@@ -169,5 +342,60 @@ fn submit_result<T: Display>(
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let answer = result.to_string();
+    let output = aoc_cli::submit(day, part, &answer);
+
+    if let Ok(output) = &output {
+        let verdict = aoc_cli::submission_verdict(output);
+        if let Err(e) = Submissions::record(day, part, &answer, verdict) {
+            eprintln!("Failed to record submission: {e}");
+        }
+    }
+
+    Some(output)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputSource;
+
+    fn parse(args: &[&str]) -> anyhow::Result<InputSource> {
+        let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+        InputSource::parse(&args)
+    }
+
+    #[test]
+    fn parses_input_source() {
+        assert_eq!(parse(&["05", "--time"]).unwrap(), InputSource::Puzzle);
+        assert_eq!(
+            parse(&["05", "--example", "2"]).unwrap(),
+            InputSource::Example(2)
+        );
+        assert_eq!(
+            parse(&["05", "--input", "other.txt"]).unwrap(),
+            InputSource::File("other.txt".into())
+        );
+        assert_eq!(parse(&["05", "--stdin"]).unwrap(), InputSource::Stdin);
+    }
+
+    #[test]
+    fn rejects_invalid_input_source() {
+        assert!(parse(&["05", "--example"]).is_err());
+        assert!(parse(&["05", "--example", "one"]).is_err());
+        assert!(parse(&["05", "--stdin", "--input", "other.txt"]).is_err());
+    }
+
+    #[test]
+    fn roundtrips_input_source_args() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::Example(1),
+            InputSource::File("teammate/05.txt".into()),
+            InputSource::Stdin,
+        ] {
+            let mut args = vec!["05".to_string()];
+            args.extend(source.to_args());
+            assert_eq!(InputSource::parse(&args).unwrap(), source);
+        }
+    }
 }
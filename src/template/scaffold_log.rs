@@ -0,0 +1,166 @@
+/// Module that keeps track of the files written by `cargo scaffold`.
+/// Only a hash of their contents is stored, so that `cargo unscaffold` can tell if a file was changed since,
+/// independent of templates or puzzle descriptions that changed in the meantime.
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use sha2::{Digest, Sha256};
+use tinyjson::JsonValue;
+
+use crate::template::workspace;
+
+static SCAFFOLD_LOG_FILE_NAME: &str = "scaffolded.json";
+
+/// Hashes of scaffolded files, by their path relative to the workspace root.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScaffoldLog {
+    files: HashMap<String, String>,
+}
+
+impl ScaffoldLog {
+    fn file_path() -> PathBuf {
+        workspace::absolute(workspace::data_dir().join(SCAFFOLD_LOG_FILE_NAME))
+    }
+
+    /// Dehydrate the log to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(Self::file_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the log from a JSON file. If not present, returns an empty log.
+    pub fn read_from_file() -> Self {
+        let path = Self::file_path();
+
+        if !path.exists() {
+            return Self::default();
+        }
+
+        let s = fs::read_to_string(path)
+            .map_err(|x| x.to_string())
+            .and_then(ScaffoldLog::try_from);
+
+        match s {
+            Ok(log) => log,
+            Err(e) => {
+                eprintln!("{e}");
+                ScaffoldLog::default()
+            }
+        }
+    }
+
+    /// Remember that `contents` were written to `path`.
+    pub fn record(&mut self, path: &Path, contents: &str) {
+        self.files.insert(key(path), hash(contents));
+    }
+
+    pub fn forget(&mut self, path: &Path) {
+        self.files.remove(&key(path));
+    }
+
+    /// Whether `contents` are what was scaffolded to `path`. `None` if `path` was not recorded.
+    pub fn matches(&self, path: &Path, contents: &str) -> Option<bool> {
+        self.files.get(&key(path)).map(|h| *h == hash(contents))
+    }
+}
+
+fn key(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+fn hash(contents: &str) -> String {
+    Sha256::digest(contents.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<ScaffoldLog> for JsonValue {
+    fn from(value: ScaffoldLog) -> Self {
+        let files = value
+            .files
+            .into_iter()
+            .map(|(path, hash)| (path, JsonValue::String(hash)))
+            .collect();
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("files".into(), JsonValue::Object(files));
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for ScaffoldLog {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_files = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("files")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected `json.files` to be an object.")?;
+
+        let files = json_files
+            .iter()
+            .map(|(path, hash)| {
+                hash.get::<String>()
+                    .map(|hash| (path.clone(), hash.clone()))
+                    .ok_or_else(|| format!("expected hash of \"{path}\" to be a string."))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(ScaffoldLog { files })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::Path;
+
+    use tinyjson::JsonValue;
+
+    use super::ScaffoldLog;
+
+    #[test]
+    fn matches_recorded_contents() {
+        let mut log = ScaffoldLog::default();
+        let path = Path::new("solutions/src/bin/05.rs");
+        log.record(path, "fn main() {}");
+
+        assert_eq!(log.matches(path, "fn main() {}"), Some(true));
+        assert_eq!(log.matches(path, "fn main() { todo!() }"), Some(false));
+        assert_eq!(log.matches(Path::new("data/inputs/05.txt"), ""), None);
+
+        log.forget(path);
+        assert_eq!(log.matches(path, "fn main() {}"), None);
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let mut log = ScaffoldLog::default();
+        log.record(Path::new("data/inputs/05.txt"), "");
+        log.record(Path::new("data/examples/05-1.txt"), "1\n2\n");
+
+        let json = JsonValue::from(log.clone()).stringify().unwrap();
+        assert_eq!(ScaffoldLog::try_from(json).unwrap(), log);
+    }
+
+    #[test]
+    fn rejects_malformed_logs() {
+        assert!(ScaffoldLog::try_from("[]".to_string()).is_err());
+        assert!(ScaffoldLog::try_from(r#"{ "files": { "a": 1 } }"#.to_string()).is_err());
+    }
+}
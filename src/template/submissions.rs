@@ -0,0 +1,247 @@
+/// Module that keeps a log of answers submitted via `cargo solve <day> --submit <part>`.
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    path::PathBuf,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{workspace, Day};
+
+static SUBMISSIONS_FILE_NAME: &str = "submissions.json";
+
+/// A single answer submitted to advent of code.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    /// Whether the answer was accepted. `None` if the response could not be interpreted.
+    pub correct: Option<bool>,
+    /// Unix timestamp (in seconds) of the submission.
+    pub timestamp: u64,
+}
+
+/// Log of all submissions, in order of submission.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// The submission log lives next to the data folders, independent of the working directory.
+    fn file_path() -> PathBuf {
+        workspace::absolute(workspace::data_dir().join(SUBMISSIONS_FILE_NAME))
+    }
+
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(Self::file_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns an empty log.
+    pub fn read_from_file() -> Self {
+        let path = Self::file_path();
+
+        if !path.exists() {
+            return Submissions::default();
+        }
+
+        let s = fs::read_to_string(path)
+            .map_err(|x| x.to_string())
+            .and_then(Submissions::try_from);
+
+        match s {
+            Ok(submissions) => submissions,
+            Err(e) => {
+                eprintln!("{e}");
+                Submissions::default()
+            }
+        }
+    }
+
+    /// Append a submission to the log file.
+    pub fn record(day: Day, part: u8, answer: &str, correct: Option<bool>) -> Result<(), Error> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        let mut submissions = Self::read_from_file();
+        submissions.data.push(Submission {
+            day,
+            part,
+            answer: answer.to_string(),
+            correct,
+            timestamp,
+        });
+        submissions.store_file()
+    }
+
+    /// The answer that was accepted for a part, if any.
+    pub fn correct_answer(&self, day: Day, part: u8) -> Option<&Submission> {
+        self.data
+            .iter()
+            .find(|s| s.day == day && s.part == part && s.correct == Some(true))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "correct".into(),
+            value.correct.map_or(JsonValue::Null, JsonValue::Boolean),
+        );
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .filter(|x| *x == 1 || *x == 2)
+            .ok_or("Expected submission.part to be 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let correct = json
+            .get("correct")
+            .map(|v| if v.is_null() { None } else { v.get::<bool>() })
+            .ok_or("Expected submission.correct to be null or boolean.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected submission.timestamp to be a number.")?;
+
+        Ok(Submission {
+            day,
+            part,
+            answer,
+            correct: correct.copied(),
+            timestamp,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Submission, Submissions};
+    use crate::day;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn roundtrips_json() {
+        let submissions = Submissions {
+            data: vec![Submission {
+                day: day!(3),
+                part: 2,
+                answer: "4361".into(),
+                correct: Some(true),
+                timestamp: 1701580000,
+            }],
+        };
+
+        let json = JsonValue::from(submissions.clone()).stringify().unwrap();
+        let parsed = Submissions::try_from(json).unwrap();
+        assert_eq!(parsed.data, submissions.data);
+    }
+
+    #[test]
+    fn finds_correct_answer() {
+        let json = r#"{ "data": [
+            { "day": "01", "part": 1, "answer": "1", "correct": false, "timestamp": 1 },
+            { "day": "01", "part": 1, "answer": "2", "correct": null, "timestamp": 2 },
+            { "day": "01", "part": 1, "answer": "3", "correct": true, "timestamp": 3 }
+        ] }"#;
+        let submissions = Submissions::try_from(json.to_string()).unwrap();
+        assert_eq!(submissions.correct_answer(day!(1), 1).unwrap().answer, "3");
+        assert!(submissions.correct_answer(day!(1), 2).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_invalid_part() {
+        let json = r#"{ "data": [{ "day": "01", "part": 3, "answer": "1", "correct": null, "timestamp": 1 }] }"#;
+        Submissions::try_from(json.to_string()).unwrap();
+    }
+}
@@ -0,0 +1,225 @@
+/// Module that renders timings as SVG charts.
+/// Charts are generated as plain strings, so no external tools are needed.
+use std::fmt::Write;
+
+use crate::template::timings::Timings;
+
+const WIDTH: f64 = 720.0;
+const BAR_HEIGHT: f64 = 10.0;
+const DAY_HEIGHT: f64 = BAR_HEIGHT * 2.0 + 8.0;
+const LABEL_WIDTH: f64 = 56.0;
+const VALUE_WIDTH: f64 = 64.0;
+const HEADER_HEIGHT: f64 = 24.0;
+const AXIS_HEIGHT: f64 = 20.0;
+
+const PART_COLORS: [&str; 2] = ["#2f81f7", "#f0883e"];
+const TEXT_COLOR: &str = "#57606a";
+const GRID_COLOR: &str = "#d0d7de";
+
+/// Maps values onto `0..=1` on a logarithmic scale spanning whole decades.
+pub struct LogScale {
+    min_exp: f64,
+    max_exp: f64,
+}
+
+impl LogScale {
+    pub fn new(values: &[f64]) -> Self {
+        let positive = values.iter().copied().filter(|x| *x > 0.0);
+        let min = positive.clone().fold(f64::INFINITY, f64::min);
+        let max = positive.fold(0_f64, f64::max);
+
+        if max == 0.0 {
+            return Self {
+                min_exp: 0.0,
+                max_exp: 1.0,
+            };
+        }
+
+        // start one decade below the smallest value, so that it still gets a visible bar.
+        let min_exp = min.log10().floor() - 1.0;
+        let max_exp = max.log10().ceil().max(min_exp + 1.0);
+        Self { min_exp, max_exp }
+    }
+
+    pub fn fraction(&self, value: f64) -> f64 {
+        if value <= 0.0 {
+            return 0.0;
+        }
+
+        ((value.log10() - self.min_exp) / (self.max_exp - self.min_exp)).clamp(0.0, 1.0)
+    }
+
+    /// Values of all decades covered by the scale, e.g. `[10, 100, 1000]`.
+    pub fn decades(&self) -> impl Iterator<Item = f64> {
+        #[allow(clippy::cast_possible_truncation)]
+        let (min, max) = (self.min_exp as i32, self.max_exp as i32);
+        (min..=max).map(|exp| 10_f64.powi(exp))
+    }
+}
+
+pub fn format_nanos(nanos: f64) -> String {
+    match nanos {
+        n if n < 1_000.0 => format!("{n:.1}ns"),
+        n if n < 1_000_000.0 => format!("{:.1}µs", n / 1_000.0),
+        n if n < 1_000_000_000.0 => format!("{:.1}ms", n / 1_000_000.0),
+        n => format!("{:.1}s", n / 1_000_000_000.0),
+    }
+}
+
+/// Horizontal bar chart with one bar per day and part, on a logarithmic scale.
+pub fn render_parts_chart(timings: &Timings) -> String {
+    let values: Vec<f64> = timings
+        .data
+        .iter()
+        .flat_map(|t| [t.part_nanos(1), t.part_nanos(2)])
+        .flatten()
+        .collect();
+    let scale = LogScale::new(&values);
+
+    let plot_width = WIDTH - LABEL_WIDTH - VALUE_WIDTH;
+    #[allow(clippy::cast_precision_loss)]
+    let plot_height = DAY_HEIGHT * timings.data.len() as f64;
+    let height = HEADER_HEIGHT + plot_height + AXIS_HEIGHT;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{height}\" \
+        viewBox=\"0 0 {WIDTH} {height}\" font-family=\"sans-serif\" font-size=\"11\">\n"
+    );
+
+    let _ = writeln!(
+        svg,
+        "<rect width=\"{WIDTH}\" height=\"{height}\" fill=\"#ffffff\"/>"
+    );
+
+    for (i, color) in PART_COLORS.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let x = LABEL_WIDTH + 80.0 * i as f64;
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{x}\" y=\"6\" width=\"{BAR_HEIGHT}\" height=\"{BAR_HEIGHT}\" fill=\"{color}\"/>\
+            <text x=\"{}\" y=\"15\" fill=\"{TEXT_COLOR}\">Part {}</text>",
+            x + BAR_HEIGHT + 4.0,
+            i + 1
+        );
+    }
+
+    for decade in scale.decades() {
+        let x = LABEL_WIDTH + scale.fraction(decade) * plot_width;
+        let _ = writeln!(
+            svg,
+            "<line x1=\"{x:.1}\" y1=\"{HEADER_HEIGHT}\" x2=\"{x:.1}\" y2=\"{}\" stroke=\"{GRID_COLOR}\"/>\
+            <text x=\"{x:.1}\" y=\"{}\" fill=\"{TEXT_COLOR}\" text-anchor=\"middle\">{}</text>",
+            HEADER_HEIGHT + plot_height,
+            height - 6.0,
+            format_decade(decade)
+        );
+    }
+
+    for (i, timing) in timings.data.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let y = HEADER_HEIGHT + DAY_HEIGHT * i as f64;
+
+        let _ = writeln!(
+            svg,
+            "<text x=\"0\" y=\"{:.1}\" fill=\"{TEXT_COLOR}\">Day {}</text>",
+            y + BAR_HEIGHT + 4.0,
+            timing.day
+        );
+
+        for (part, color) in PART_COLORS.iter().enumerate() {
+            #[allow(clippy::cast_precision_loss)]
+            let bar_y = y + BAR_HEIGHT * part as f64;
+
+            #[allow(clippy::cast_possible_truncation)]
+            let Some(nanos) = timing.part_nanos(part as u8 + 1) else {
+                continue;
+            };
+
+            let width = scale.fraction(nanos) * plot_width;
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{LABEL_WIDTH}\" y=\"{bar_y:.1}\" width=\"{width:.1}\" height=\"{}\" fill=\"{color}\"/>\
+                <text x=\"{:.1}\" y=\"{:.1}\" fill=\"{TEXT_COLOR}\" font-size=\"9\">{}</text>",
+                BAR_HEIGHT - 1.0,
+                LABEL_WIDTH + width + 4.0,
+                bar_y + BAR_HEIGHT - 2.0,
+                format_nanos(nanos)
+            );
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn format_decade(nanos: f64) -> String {
+    match nanos {
+        n if n < 1_000.0 => format!("{n}ns"),
+        n if n < 1_000_000.0 => format!("{}µs", n / 1_000.0),
+        n if n < 1_000_000_000.0 => format!("{}ms", n / 1_000_000.0),
+        n => format!("{}s", n / 1_000_000_000.0),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_decade, format_nanos, render_parts_chart, LogScale};
+    use crate::{
+        day,
+        template::timings::{Timing, Timings},
+    };
+
+    #[test]
+    fn scales_logarithmically() {
+        let scale = LogScale::new(&[1e3, 1e6]);
+        assert_eq!(scale.fraction(1e6), 1.0);
+        assert_eq!(scale.fraction(0.0), 0.0);
+        assert!(scale.fraction(1e3) > 0.0);
+        assert!((scale.fraction(1e4) - scale.fraction(1e3) - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn lists_decades() {
+        let scale = LogScale::new(&[50.0, 2e3]);
+        assert_eq!(
+            scale.decades().collect::<Vec<_>>(),
+            vec![1.0, 10.0, 100.0, 1000.0, 10000.0]
+        );
+    }
+
+    #[test]
+    fn formats_nanos() {
+        assert_eq!(format_nanos(74.13), "74.1ns");
+        assert_eq!(format_nanos(1_500.0), "1.5µs");
+        assert_eq!(format_nanos(2e9), "2.0s");
+        assert_eq!(format_decade(1e4), "10µs");
+    }
+
+    #[test]
+    fn renders_bar_per_part() {
+        let timings = Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    part_1: Some("10ms".into()),
+                    part_2: Some("20µs".into()),
+                    total_nanos: 1.002e+7,
+                    conditions: None,
+                },
+                Timing {
+                    day: day!(2),
+                    part_1: Some("30ns".into()),
+                    part_2: None,
+                    total_nanos: 30.0,
+                    conditions: None,
+                },
+            ],
+        };
+
+        let svg = render_parts_chart(&timings);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("height=\"9\"").count(), 3);
+        assert!(svg.contains(">Day 02</text>"));
+    }
+}
@@ -1,9 +1,17 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    path::PathBuf,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{bench_env::BenchConditions, workspace, Day};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+static TIMINGS_FILE_NAME: &str = "timings.json";
+static HISTORY_FILE_NAME: &str = "timings-history.json";
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
@@ -12,6 +20,7 @@ pub struct Timing {
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    pub conditions: Option<BenchConditions>,
 }
 
 /// Represents benchmark times for a set of days.
@@ -21,17 +30,36 @@ pub struct Timings {
     pub data: Vec<Timing>,
 }
 
+/// A snapshot of the stored timings, taken whenever they are updated.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub recorded_at: u64,
+    pub timings: Timings,
+}
+
+/// Every snapshot of the stored timings, oldest first.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct TimingsHistory {
+    pub entries: Vec<HistoryEntry>,
+}
+
 impl Timings {
+    fn file_path() -> PathBuf {
+        workspace::absolute(workspace::data_dir().join(TIMINGS_FILE_NAME))
+    }
+
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(Self::file_path())?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
     pub fn read_from_file() -> Self {
-        let s = fs::read_to_string(TIMINGS_FILE_PATH)
+        let s = fs::read_to_string(Self::file_path())
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from);
 
@@ -44,6 +72,18 @@ impl Timings {
         }
     }
 
+    /// Rehydrate timings from a JSON file, failing if it is malformed. Returns `None` if not present.
+    pub fn try_read_from_file() -> Result<Option<Self>, String> {
+        let path = Self::file_path();
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let s = fs::read_to_string(path).map_err(|x| x.to_string())?;
+        Timings::try_from(s).map(Some)
+    }
+
     /// Merge two sets of timings, overwriting `self` with `other` if present.
     pub fn merge(&self, new: &Self) -> Self {
         let mut data: Vec<Timing> = vec![];
@@ -58,7 +98,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|t| t.day);
         Timings { data }
     }
 
@@ -74,6 +114,77 @@ impl Timings {
     }
 }
 
+impl TimingsHistory {
+    fn file_path() -> PathBuf {
+        workspace::absolute(workspace::data_dir().join(HISTORY_FILE_NAME))
+    }
+
+    /// Append a snapshot of `timings`, taken now, to the history file.
+    pub fn append(timings: &Timings) -> Result<(), Error> {
+        let mut history = Self::read_from_file();
+        let recorded_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        history.entries.push(HistoryEntry {
+            recorded_at,
+            timings: timings.clone(),
+        });
+
+        let json = JsonValue::from(history);
+        let mut file = fs::File::create(Self::file_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the history from a JSON file. If not present, returns an empty history.
+    pub fn read_from_file() -> Self {
+        let path = Self::file_path();
+
+        if !path.exists() {
+            return Self::default();
+        }
+
+        let s = fs::read_to_string(path)
+            .map_err(|x| x.to_string())
+            .and_then(TimingsHistory::try_from);
+
+        match s {
+            Ok(history) => history,
+            Err(e) => {
+                eprintln!("{e}");
+                TimingsHistory::default()
+            }
+        }
+    }
+}
+
+impl Timing {
+    /// Duration of a single part in nanoseconds, if it was benched.
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+        .and_then(parse_duration_nanos)
+    }
+}
+
+/// Parses a duration as formatted by [`std::time::Duration`]'s `Debug` impl (e.g. `74.13ns`) into nanoseconds.
+pub fn parse_duration_nanos(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    match s {
+        s if s.contains("ns") => parse_to_float(s, "ns"),
+        s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+        s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
+    }
+}
+
+fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
+    s.split(postfix).next()?.parse().ok()
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<Timings> for JsonValue {
@@ -94,7 +205,14 @@ impl TryFrom<String> for Timings {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        Timings::try_from(&json)
+    }
+}
 
+impl TryFrom<&JsonValue> for Timings {
+    type Error = String;
+
+    fn try_from(json: &JsonValue) -> Result<Self, Self::Error> {
         let json_data = json
             .get::<HashMap<String, JsonValue>>()
             .ok_or("expected JSON document to be an object.")?
@@ -114,6 +232,74 @@ impl TryFrom<String> for Timings {
 
 /* -------------------------------------------------------------------------- */
 
+impl From<TimingsHistory> for JsonValue {
+    fn from(value: TimingsHistory) -> Self {
+        let entries = value
+            .entries
+            .into_iter()
+            .map(|entry| {
+                let mut map: HashMap<String, JsonValue> = HashMap::new();
+                #[allow(clippy::cast_precision_loss)]
+                map.insert(
+                    "recorded_at".into(),
+                    JsonValue::Number(entry.recorded_at as f64),
+                );
+                map.insert("timings".into(), JsonValue::from(entry.timings));
+                JsonValue::Object(map)
+            })
+            .collect();
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("entries".into(), JsonValue::Array(entries));
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for TimingsHistory {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_entries = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("entries")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("expected `json.entries` to be an array.")?;
+
+        let mut entries = vec![];
+
+        for entry in json_entries {
+            let entry = entry
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("expected history entry to be an object.")?;
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let recorded_at = entry
+                .get("recorded_at")
+                .and_then(|v| v.get::<f64>())
+                .map(|v| *v as u64)
+                .ok_or("expected entry.recorded_at to be a number.")?;
+
+            let timings = entry
+                .get("timings")
+                .ok_or("expected entry to have key `timings`.")
+                .map_err(String::from)
+                .and_then(Timings::try_from)?;
+
+            entries.push(HistoryEntry {
+                recorded_at,
+                timings,
+            });
+        }
+
+        Ok(TimingsHistory { entries })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
@@ -140,6 +326,14 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "conditions".into(),
+            value
+                .conditions
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -173,11 +367,18 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: older timing files do not contain conditions, treat them as unknown.
+        let conditions = match json.get("conditions") {
+            Some(v) if !v.is_null() => Some(BenchConditions::try_from(v)?),
+            _ => None,
+        };
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
             total_nanos,
+            conditions,
         })
     }
 }
@@ -198,18 +399,21 @@ mod tests {
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    conditions: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    conditions: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
                     total_nanos: 4e+10,
+                    conditions: None,
                 },
             ],
         }
@@ -228,6 +432,16 @@ mod tests {
             assert_eq!(timing.part_1, Some("1ms".to_string()));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.conditions, None);
+        }
+
+        #[test]
+        fn handles_json_timings_with_conditions() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000, "conditions": { "pinned_cpu": 2, "niceness": 0, "governor": "performance", "load_average": 0.5 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let conditions = timings.data[0].conditions.clone().unwrap();
+            assert_eq!(conditions.pinned_cpu, Some(2));
+            assert_eq!(conditions.governor, Some("performance".to_string()));
         }
 
         #[test]
@@ -254,6 +468,7 @@ mod tests {
 
     mod serialization {
         use super::get_mock_timings;
+        use crate::template::timings::{HistoryEntry, TimingsHistory};
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
@@ -273,6 +488,21 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn round_trips_history() {
+            let history = TimingsHistory {
+                entries: vec![HistoryEntry {
+                    recorded_at: 1_733_011_200,
+                    timings: get_mock_timings(),
+                }],
+            };
+            let json = JsonValue::from(history).stringify().unwrap();
+            let history = TimingsHistory::try_from(json).unwrap();
+            assert_eq!(history.entries.len(), 1);
+            assert_eq!(history.entries[0].recorded_at, 1_733_011_200);
+            assert_eq!(history.entries[0].timings.data.len(), 3);
+        }
     }
 
     mod is_day_complete {
@@ -289,10 +519,11 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    conditions: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -303,10 +534,11 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    conditions: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -317,10 +549,11 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    conditions: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -341,6 +574,7 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    conditions: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -361,6 +595,7 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    conditions: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -0,0 +1,127 @@
+/// Module that describes the layout of the workspace.
+/// Solutions are binaries of a separate package, while data files live next to the template package.
+/// Paths are available relative to the workspace root, for output and links, and as absolute paths,
+/// so that commands work independent of the working directory.
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use crate::template::Day;
+
+/// Name of the package that contains the solutions.
+pub static SOLUTIONS_PACKAGE: &str = "solutions";
+
+static SOLUTIONS_DIR: &str = "solutions";
+static BIN_DIR: &str = "src/bin";
+static DATA_DIR: &str = "data";
+static PROFILES_DIR: &str = "profiles";
+static TARGET_DIR: &str = "target";
+static README_FILE: &str = "README.md";
+
+/// The workspace root, which is also the root of the template package.
+pub fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// The directory of the solutions package, relative to the workspace root.
+pub fn solutions_dir() -> PathBuf {
+    PathBuf::from(SOLUTIONS_DIR)
+}
+
+/// The directory that contains one binary per day, relative to the workspace root.
+pub fn bin_dir() -> PathBuf {
+    solutions_dir().join(BIN_DIR)
+}
+
+/// The source file of a day's solution, relative to the workspace root.
+pub fn bin_path(day: Day) -> PathBuf {
+    bin_dir().join(day.to_string()).with_extension("rs")
+}
+
+/// The root of all data files, relative to the workspace root.
+pub fn data_dir() -> PathBuf {
+    PathBuf::from(DATA_DIR)
+}
+
+/// The directory for CPU and heap profiles, relative to the workspace root.
+pub fn profiles_dir() -> PathBuf {
+    data_dir().join(PROFILES_DIR)
+}
+
+/// The readme that holds the benchmark and stars tables, relative to the workspace root.
+pub fn readme_path() -> PathBuf {
+    PathBuf::from(README_FILE)
+}
+
+/// Resolve a path relative to the workspace root to an absolute path.
+pub fn absolute(path: impl AsRef<Path>) -> PathBuf {
+    root().join(path)
+}
+
+/// Whether a day has a solution. Days without one have not been scaffolded yet.
+pub fn has_solution(day: Day) -> bool {
+    absolute(bin_path(day)).exists()
+}
+
+/// The directory cargo builds into. Respects `CARGO_TARGET_DIR`.
+pub fn target_dir() -> PathBuf {
+    env::var_os("CARGO_TARGET_DIR").map_or_else(|| absolute(TARGET_DIR), PathBuf::from)
+}
+
+/// Arguments that select the binary of a day when invoking cargo.
+pub fn cargo_bin_args(day: Day) -> [String; 4] {
+    [
+        "--bin".into(),
+        day.to_string(),
+        "--package".into(),
+        SOLUTIONS_PACKAGE.into(),
+    ]
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{fs, path::Path};
+
+    use super::{
+        absolute, bin_dir, bin_path, data_dir, has_solution, root, solutions_dir, SOLUTIONS_PACKAGE,
+    };
+    use crate::{day, template::Day};
+
+    #[test]
+    fn bin_path_is_in_solutions_package() {
+        assert_eq!(bin_path(day!(5)), Path::new("solutions/src/bin/05.rs"));
+        assert!(bin_path(day!(5)).starts_with(solutions_dir()));
+    }
+
+    #[test]
+    fn solutions_package_exists() {
+        let manifest = fs::read_to_string(absolute(solutions_dir()).join("Cargo.toml")).unwrap();
+        assert!(manifest.contains(&format!("name = \"{SOLUTIONS_PACKAGE}\"")));
+
+        let workspace = fs::read_to_string(root().join("Cargo.toml")).unwrap();
+        assert!(workspace.contains(&format!("\"{}\"", solutions_dir().display())));
+    }
+
+    #[test]
+    fn directories_exist() {
+        assert!(absolute(bin_dir()).is_dir());
+        assert!(absolute(data_dir()).is_dir());
+    }
+
+    #[test]
+    fn finds_scaffolded_solutions() {
+        for entry in fs::read_dir(absolute(bin_dir())).unwrap() {
+            let path = entry.unwrap().path();
+            let day = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse::<Day>().ok());
+
+            if let Some(day) = day {
+                assert_eq!(path, absolute(bin_path(day)));
+                assert!(has_solution(day));
+            }
+        }
+    }
+}